@@ -58,4 +58,220 @@ pub enum NovaError {
     
     #[msg("Invalid timestamp")]
     InvalidTimestamp,
+    
+    #[msg("Underwriter share mint has not been initialized for this pool")]
+    ShareMintNotInitialized,
+    
+    #[msg("Share amount is invalid")]
+    InvalidShareAmount,
+    
+    #[msg("No capital withdrawal has been requested")]
+    NoPendingWithdrawal,
+    
+    #[msg("Capital withdrawal cooldown has not elapsed")]
+    WithdrawalCooldownActive,
+    
+    #[msg("Capital is locked while claims are under validation")]
+    CapitalLocked,
+    
+    #[msg("Pool config change is still timelocked")]
+    ConfigTimelockActive,
+    
+    #[msg("Pool directory is full")]
+    PoolDirectoryFull,
+    
+    #[msg("Billing and grace periods must be valid durations")]
+    InvalidBillingPeriod,
+    
+    #[msg("Coverage is not overdue")]
+    CoverageNotOverdue,
+    
+    #[msg("Incident occurred outside a paid coverage period")]
+    IncidentNotCovered,
+    
+    #[msg("Prepay discount must be below 100%")]
+    InvalidPrepayDiscount,
+    
+    #[msg("Number of premium periods is invalid")]
+    InvalidPremiumPeriods,
+    
+    #[msg("Member has open claims")]
+    OpenClaimsExist,
+    
+    #[msg("Pool is not active")]
+    PoolNotActive,
+    
+    #[msg("Invalid pool status transition")]
+    InvalidStatusTransition,
+    
+    #[msg("Pool still has outstanding claims")]
+    OutstandingClaims,
+    
+    #[msg("Funds must be withdrawn from the yield vault first")]
+    YieldNotWithdrawn,
+    
+    #[msg("Underwriter shares are still outstanding")]
+    OutstandingShares,
+    
+    #[msg("Pool still has member accounts")]
+    MembersRemaining,
+    
+    #[msg("This instruction category is paused by the pool guardian")]
+    CategoryPaused,
+    
+    #[msg("Invalid pause categories")]
+    InvalidPauseCategories,
+    
+    #[msg("Multisig threshold must be between 1 and the number of signers")]
+    InvalidMultisigThreshold,
+    
+    #[msg("Multisig signers must be unique")]
+    DuplicateMultisigSigner,
+    
+    #[msg("Signer is not an owner of this multisig")]
+    NotMultisigSigner,
+    
+    #[msg("Multisig transaction does not have enough approvals")]
+    InsufficientApprovals,
+    
+    #[msg("Multisig transaction has already been executed")]
+    TransactionAlreadyExecuted,
+    
+    #[msg("Multisig signers changed since this transaction was proposed")]
+    StaleMultisigTransaction,
+    
+    #[msg("Multisig transaction exceeds size limits")]
+    TransactionTooLarge,
+    
+    #[msg("No authority transfer is pending")]
+    NoPendingAuthority,
+    
+    #[msg("Invalid waiting period")]
+    InvalidWaitingPeriod,
+    
+    #[msg("Incident occurred during the coverage waiting period")]
+    WaitingPeriodNotElapsed,
+    
+    #[msg("Invalid deductible or coinsurance terms")]
+    InvalidCostSharing,
+    
+    #[msg("Claim exceeds the member's annual aggregate limit")]
+    AnnualLimitExceeded,
+    
+    #[msg("Member has reached the maximum number of claims this policy year")]
+    AnnualClaimCountExceeded,
+    
+    #[msg("Claim can no longer be withdrawn")]
+    ClaimNotWithdrawable,
+    
+    #[msg("Invalid appeal terms")]
+    InvalidAppealTerms,
+    
+    #[msg("Claim cannot be appealed")]
+    ClaimNotAppealable,
+    
+    #[msg("Appeal window has closed")]
+    AppealWindowClosed,
+    
+    #[msg("No appeal bond to refund")]
+    NoAppealBond,
+    
+    #[msg("Invalid voting period")]
+    InvalidVotingPeriod,
+    
+    #[msg("Voting deadline has not passed")]
+    VotingDeadlineNotReached,
+    
+    #[msg("All assigned validators have voted")]
+    NoUnresponsiveValidators,
+    
+    #[msg("Commit deadline has passed")]
+    CommitDeadlinePassed,
+    
+    #[msg("Reveal phase has not started")]
+    RevealPhaseNotStarted,
+    
+    #[msg("Reveal deadline has passed")]
+    RevealDeadlinePassed,
+    
+    #[msg("Reveal deadline has not passed")]
+    RevealDeadlineNotReached,
+    
+    #[msg("No vote commitment found for validator")]
+    NoVoteCommitment,
+    
+    #[msg("Revealed vote does not match commitment")]
+    InvalidVoteReveal,
+    
+    #[msg("Approved amount must be positive and not exceed the amount requested")]
+    InvalidApprovedAmount,
+    
+    #[msg("Claim has not been decided")]
+    ClaimNotDecided,
+    
+    #[msg("Validation has already been settled")]
+    ValidationAlreadySettled,
+    
+    #[msg("Invalid slashing terms")]
+    InvalidSlashingTerms,
+    
+    #[msg("Minority votes on this claim must be settled first")]
+    MinoritySettlementPending,
+    
+    #[msg("Invalid unstake cooldown")]
+    InvalidUnstakeCooldown,
+    
+    #[msg("Validator is assigned to an open claim")]
+    ValidatorHasOpenClaims,
+    
+    #[msg("Validator has already requested to unstake")]
+    UnstakeAlreadyRequested,
+    
+    #[msg("Validator has not requested to unstake")]
+    UnstakeNotRequested,
+    
+    #[msg("Unstake cooldown has not elapsed")]
+    UnstakeCooldownActive,
+    
+    #[msg("Invalid validator reward terms")]
+    InvalidValidatorRewardTerms,
+    
+    #[msg("Validator rewards vault is required")]
+    ValidatorRewardsVaultRequired,
+    
+    #[msg("No validator rewards to claim")]
+    NoValidatorRewards,
+    
+    #[msg("Validator rewards must be claimed first")]
+    UnclaimedValidatorRewards,
+    
+    #[msg("Invalid minimum validator reputation")]
+    InvalidMinReputation,
+    
+    #[msg("Invalid linked wallets")]
+    InvalidLinkedWallets,
+    
+    #[msg("Validator has no declared conflict with this claim")]
+    NoConflictOfInterest,
+    
+    #[msg("Validator cannot recuse after committing a vote")]
+    CannotRecuseAfterCommit,
+    
+    #[msg("Validator is a member of this pool")]
+    ValidatorIsPoolMember,
+    
+    #[msg("Premiums cannot be prepaid beyond the pool's maximum prepay horizon")]
+    PrepayHorizonExceeded,
+    
+    #[msg("Previous pool directory page must be provided and full")]
+    PreviousDirectoryPageNotFull,
+    
+    #[msg("All first-round votes must be settled before the claim can be appealed")]
+    FirstRoundNotSettled,
+    
+    #[msg("Pool liabilities exceed its assets; outstanding shares have no backing")]
+    UnbackedShares,
+    
+    #[msg("A rejected claim can still be appealed")]
+    AppealWindowOpen,
 }
@@ -0,0 +1,384 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::*;
+use crate::instructions::vrf_integration::{generate_randomness, select_random_validators};
+use crate::state::*;
+
+/// Appeal a rejected claim by posting the pool's appeal bond. A larger panel that
+/// excludes the original validators is drawn, and its decision overrides the first round.
+pub fn appeal_claim(ctx: Context<AppealClaim>) -> Result<()> {
+    let clock = Clock::get()?;
+    let pool = &ctx.accounts.pool;
+    let claim = &ctx.accounts.claim_request;
+
+    pool.require_not_paused(PauseCategory::CLAIMS)?;
+    // An appeal re-decides a claim the pool already took on, so it is still heard
+    // while winding down; finalize_wind_down waits for the last appeal window
+    require!(
+        matches!(
+            pool.status,
+            PoolStatus::Active | PoolStatus::Paused | PoolStatus::WindingDown
+        ),
+        NovaError::PoolNotActive
+    );
+
+    require!(
+        claim.status == ClaimStatus::Rejected && !claim.appealed,
+        NovaError::ClaimNotAppealable
+    );
+    let appeal_deadline = claim
+        .resolved_at
+        .ok_or(NovaError::ClaimNotAppealable)?
+        .checked_add(pool.appeal_window)
+        .ok_or(NovaError::MathOverflow)?;
+    require!(
+        clock.unix_timestamp <= appeal_deadline,
+        NovaError::AppealWindowClosed
+    );
+
+    // First-round votes must be settled so their rewards and slashing are not lost
+    require!(
+        claim.validations.iter().all(|v| v.settled),
+        NovaError::FirstRoundNotSettled
+    );
+
+    // Draw the appeal panel from validators not involved in the first round
+    let claim_key = claim.key();
+    let mut excluded = claim.validators_assigned.clone();
+    excluded.extend(claim.conflicted_validators());
+    let randomness = generate_randomness(
+        &claim_key,
+        &pool.key(),
+        clock.unix_timestamp,
+        clock.slot,
+    );
+    let panel = select_random_validators(
+        &randomness,
+        &ctx.accounts.validator_registry,
+        pool,
+        &excluded,
+        pool.appeal_panel_size as usize,
+    )?;
+
+    let voting_deadline = clock
+        .unix_timestamp
+        .checked_add(pool.voting_period)
+        .ok_or(NovaError::MathOverflow)?;
+
+    // Post the appeal bond into the pool vault
+    let bond = pool.appeal_bond;
+    if bond > 0 {
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.claimant_token_account.to_account_info(),
+                to: ctx.accounts.pool_vault.to_account_info(),
+                authority: ctx.accounts.claimant.to_account_info(),
+            },
+        );
+        token::transfer(transfer_ctx, bond)?;
+    }
+
+    ctx.accounts.validator_registry.record_assignments(&panel)?;
+
+    let claim = &mut ctx.accounts.claim_request;
+    claim.original_validators = claim.validators_assigned.clone();
+    claim.original_majority = claim
+        .validations
+        .iter()
+        .filter(|v| !v.approved)
+        .map(|v| v.validator)
+        .collect();
+    claim.validators_assigned = panel.clone();
+    claim.validations = Vec::new();
+    claim.approvals = 0;
+    claim.rejections = 0;
+    claim.status = ClaimStatus::UnderValidation;
+    claim.vrf_result = Some(randomness);
+    claim.commitments = Vec::new();
+    claim.reveal_deadline = 0;
+    claim.voting_deadline = voting_deadline;
+    claim.resolved_at = None;
+    claim.appealed = true;
+    claim.appeal_bond = bond;
+
+    // The claim is open again until the appeal panel decides
+    let pool = &mut ctx.accounts.pool;
+    pool.appeal_bonds_held = pool
+        .appeal_bonds_held
+        .checked_add(bond)
+        .ok_or(NovaError::MathOverflow)?;
+    pool.active_claims = pool
+        .active_claims
+        .checked_add(1)
+        .ok_or(NovaError::MathOverflow)?;
+    pool.claims_under_validation = pool
+        .claims_under_validation
+        .checked_add(1)
+        .ok_or(NovaError::MathOverflow)?;
+
+    let user_coverage = &mut ctx.accounts.user_coverage;
+    user_coverage.roll_policy_year(clock.unix_timestamp);
+    user_coverage.open_claims = user_coverage
+        .open_claims
+        .checked_add(1)
+        .ok_or(NovaError::MathOverflow)?;
+    if claim.created_at >= user_coverage.policy_year_start {
+        user_coverage.claimed_this_year = user_coverage
+            .claimed_this_year
+            .checked_add(claim.amount_requested)
+            .ok_or(NovaError::MathOverflow)?;
+    }
+
+    emit!(ClaimAppealedEvent {
+        claim_id: claim_key,
+        claimant: claim.claimant,
+        pool: pool.key(),
+        bond,
+        panel,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Claim {} appealed with {} USDC bond to a panel of {}",
+        claim_key,
+        bond,
+        claim.validators_assigned.len()
+    );
+
+    Ok(())
+}
+
+/// Refund the appeal bond after the appeal panel overturned the rejection
+pub fn refund_appeal_bond(ctx: Context<RefundAppealBond>) -> Result<()> {
+    let clock = Clock::get()?;
+    let claim = &ctx.accounts.claim_request;
+
+    ctx.accounts
+        .pool
+        .require_not_paused(PauseCategory::DISTRIBUTION)?;
+    require!(claim.appeal_overturned, NovaError::NoAppealBond);
+    require!(claim.appeal_bond > 0, NovaError::NoAppealBond);
+
+    let bond = claim.appeal_bond;
+    let pool = &ctx.accounts.pool;
+    let seeds = pool.signer_seeds();
+    let signer = &[&seeds[..]];
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.pool_vault.to_account_info(),
+            to: ctx.accounts.claimant_token_account.to_account_info(),
+            authority: pool.to_account_info(),
+        },
+        signer,
+    );
+    token::transfer(transfer_ctx, bond)?;
+
+    let claim = &mut ctx.accounts.claim_request;
+    claim.appeal_bond = 0;
+    let pool = &mut ctx.accounts.pool;
+    pool.appeal_bonds_held = pool.appeal_bonds_held.saturating_sub(bond);
+
+    emit!(AppealBondRefundedEvent {
+        claim_id: claim.key(),
+        claimant: claim.claimant,
+        amount: bond,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Refunded {} USDC appeal bond for claim {}", bond, claim.key());
+
+    Ok(())
+}
+
+/// Apply the reputation penalty to an original majority voter whose decision
+/// was overturned on appeal. Permissionless; each voter is penalized once.
+pub fn penalize_overturned_validator(ctx: Context<PenalizeOverturnedValidator>) -> Result<()> {
+    let clock = Clock::get()?;
+    let claim = &mut ctx.accounts.claim_request;
+    let validator_stake = &mut ctx.accounts.validator_stake;
+
+    require!(claim.appeal_overturned, NovaError::ClaimNotAppealable);
+    let position = claim
+        .original_majority
+        .iter()
+        .position(|v| *v == validator_stake.validator)
+        .ok_or(NovaError::UnauthorizedValidator)?;
+    claim.original_majority.remove(position);
+
+    validator_stake.reputation_score = validator_stake
+        .reputation_score
+        .saturating_sub(ValidatorStake::OVERTURNED_PENALTY);
+    ctx.accounts
+        .validator_registry
+        .sync_validator(validator_stake);
+
+    emit!(OverturnedValidatorPenalizedEvent {
+        claim_id: claim.key(),
+        validator: validator_stake.validator,
+        reputation_score: validator_stake.reputation_score,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Validator {} penalized -{} reputation for overturned vote on claim {}",
+        validator_stake.validator,
+        ValidatorStake::OVERTURNED_PENALTY,
+        claim.key()
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// Account Validation Contexts
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct AppealClaim<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"claim",
+            pool.key().as_ref(),
+            &claim_request.sequence.to_le_bytes()
+        ],
+        bump = claim_request.bump,
+        constraint = claim_request.claimant == claimant.key() @ NovaError::Unauthorized
+    )]
+    pub claim_request: Box<Account<'info, ClaimRequest>>,
+
+    #[account(mut)]
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    #[account(
+        mut,
+        seeds = [b"coverage", claimant.key().as_ref(), pool.key().as_ref()],
+        bump = user_coverage.bump,
+        constraint = user_coverage.pool == pool.key() @ NovaError::InactiveCoverage
+    )]
+    pub user_coverage: Box<Account<'info, UserCoverage>>,
+
+    #[account(
+        mut,
+        seeds = [b"validator_registry", pool.key().as_ref()],
+        bump = validator_registry.bump,
+        constraint = validator_registry.pool == pool.key() @ NovaError::InactiveCoverage
+    )]
+    pub validator_registry: Box<Account<'info, ValidatorRegistry>>,
+
+    #[account(
+        mut,
+        constraint = pool_vault.key() == pool.vault @ NovaError::Unauthorized
+    )]
+    pub pool_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = claimant_token_account.owner == claimant.key() @ NovaError::Unauthorized,
+        constraint = claimant_token_account.mint == pool_vault.mint @ NovaError::InvalidPremiumAmount
+    )]
+    pub claimant_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct RefundAppealBond<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"claim",
+            pool.key().as_ref(),
+            &claim_request.sequence.to_le_bytes()
+        ],
+        bump = claim_request.bump,
+        constraint = claim_request.claimant == claimant.key() @ NovaError::Unauthorized
+    )]
+    pub claim_request: Box<Account<'info, ClaimRequest>>,
+
+    #[account(mut)]
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    #[account(
+        mut,
+        constraint = pool_vault.key() == pool.vault @ NovaError::Unauthorized
+    )]
+    pub pool_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = claimant_token_account.owner == claimant.key() @ NovaError::Unauthorized,
+        constraint = claimant_token_account.mint == pool_vault.mint @ NovaError::InvalidPremiumAmount
+    )]
+    pub claimant_token_account: Box<Account<'info, TokenAccount>>,
+
+    pub claimant: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct PenalizeOverturnedValidator<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"claim",
+            pool.key().as_ref(),
+            &claim_request.sequence.to_le_bytes()
+        ],
+        bump = claim_request.bump
+    )]
+    pub claim_request: Box<Account<'info, ClaimRequest>>,
+
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    #[account(
+        mut,
+        seeds = [b"validator", validator_stake.validator.as_ref(), pool.key().as_ref()],
+        bump = validator_stake.bump
+    )]
+    pub validator_stake: Box<Account<'info, ValidatorStake>>,
+
+    #[account(
+        mut,
+        seeds = [b"validator_registry", pool.key().as_ref()],
+        bump = validator_registry.bump
+    )]
+    pub validator_registry: Box<Account<'info, ValidatorRegistry>>,
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+#[event]
+pub struct ClaimAppealedEvent {
+    pub claim_id: Pubkey,
+    pub claimant: Pubkey,
+    pub pool: Pubkey,
+    pub bond: u64,
+    pub panel: Vec<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AppealBondRefundedEvent {
+    pub claim_id: Pubkey,
+    pub claimant: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OverturnedValidatorPenalizedEvent {
+    pub claim_id: Pubkey,
+    pub validator: Pubkey,
+    pub reputation_score: u32,
+    pub timestamp: i64,
+}
@@ -0,0 +1,568 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, MintTo, Token, TokenAccount, Transfer};
+
+use crate::errors::*;
+use crate::state::*;
+
+/// Enable underwriter capital deposits by creating the pool's share mint
+pub fn initialize_share_mint(
+    ctx: Context<InitializeShareMint>,
+    withdrawal_cooldown: i64,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let clock = Clock::get()?;
+
+    require!(pool.share_mint.is_none(), NovaError::AlreadyInitialized);
+    require!(withdrawal_cooldown >= 0, NovaError::InvalidTimestamp);
+
+    let share_mint_key = ctx.accounts.share_mint.key();
+    pool.share_mint = Some(share_mint_key);
+    pool.withdrawal_cooldown = withdrawal_cooldown;
+
+    emit!(ShareMintInitializedEvent {
+        pool: pool.key(),
+        share_mint: share_mint_key,
+        withdrawal_cooldown,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Share mint {} initialized for pool {} with {}s withdrawal cooldown",
+        share_mint_key,
+        pool.key(),
+        withdrawal_cooldown
+    );
+
+    Ok(())
+}
+
+/// Deposit underwriting capital into the pool vault in exchange for share tokens
+pub fn deposit_capital(ctx: Context<DepositCapital>, amount: u64) -> Result<()> {
+    let clock = Clock::get()?;
+
+    ctx.accounts.pool.require_not_paused(PauseCategory::MEMBERSHIP)?;
+
+    // No new capital once the pool is winding down or its residual has been fixed
+    require!(
+        matches!(
+            ctx.accounts.pool.status,
+            PoolStatus::Active | PoolStatus::Paused
+        ),
+        NovaError::PoolNotActive
+    );
+    require!(amount > 0, NovaError::InvalidCoverageAmount);
+
+    // Price shares against NAV before the deposit lands in the vault. Funds already
+    // in the pool before the first deposit (member premiums) are credited to
+    // reserved shares at 1:1, so the first underwriter cannot claim them.
+    let nav = ctx.accounts.pool.net_asset_value(ctx.accounts.pool_vault.amount)?;
+    let share_supply = ctx.accounts.share_mint.supply;
+    if ctx.accounts.pool.effective_share_supply(share_supply)? == 0 {
+        ctx.accounts.pool.reserved_shares = nav;
+    }
+    let effective_supply = ctx.accounts.pool.effective_share_supply(share_supply)?;
+    let shares = calculate_shares_for_deposit(amount, nav, effective_supply)?;
+
+    // Transfer USDC from provider to pool vault
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.provider_token_account.to_account_info(),
+            to: ctx.accounts.pool_vault.to_account_info(),
+            authority: ctx.accounts.provider.to_account_info(),
+        },
+    );
+    token::transfer(transfer_ctx, amount)?;
+
+    // Mint shares to provider, signed by the pool PDA
+    let seeds = ctx.accounts.pool.signer_seeds();
+    let signer = &[&seeds[..]];
+    let mint_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        MintTo {
+            mint: ctx.accounts.share_mint.to_account_info(),
+            to: ctx.accounts.provider_share_account.to_account_info(),
+            authority: ctx.accounts.pool.to_account_info(),
+        },
+        signer,
+    );
+    token::mint_to(mint_ctx, shares)?;
+
+    let provider_key = ctx.accounts.provider.key();
+    let pool_key = ctx.accounts.pool.key();
+
+    // Record deposit on the provider's position
+    let position = &mut ctx.accounts.underwriter_position;
+    if position.provider == Pubkey::default() {
+        position.provider = provider_key;
+        position.pool = pool_key;
+        position.capital_deposited = 0;
+        position.capital_withdrawn = 0;
+        position.shares_pending_withdrawal = 0;
+        position.withdrawal_requested_at = 0;
+        position.bump = *ctx.bumps.get("underwriter_position").unwrap();
+    }
+    position.capital_deposited = position
+        .capital_deposited
+        .checked_add(amount)
+        .ok_or(NovaError::MathOverflow)?;
+
+    // Capital is available to pay claims
+    let pool = &mut ctx.accounts.pool;
+    pool.total_pooled = pool
+        .total_pooled
+        .checked_add(amount)
+        .ok_or(NovaError::MathOverflow)?;
+
+    emit!(CapitalDepositedEvent {
+        provider: provider_key,
+        pool: pool_key,
+        amount,
+        shares_minted: shares,
+        net_asset_value: nav,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Underwriter {} deposited {} USDC into pool {} for {} shares",
+        provider_key,
+        amount,
+        pool_key,
+        shares
+    );
+
+    Ok(())
+}
+
+/// Queue shares for withdrawal, starting the pool's withdrawal cooldown
+pub fn request_capital_withdrawal(
+    ctx: Context<RequestCapitalWithdrawal>,
+    shares: u64,
+) -> Result<()> {
+    let position = &mut ctx.accounts.underwriter_position;
+    let pool = &ctx.accounts.pool;
+    let clock = Clock::get()?;
+
+    require!(shares > 0, NovaError::InvalidShareAmount);
+    require!(
+        ctx.accounts.provider_share_account.amount >= shares,
+        NovaError::InvalidShareAmount
+    );
+
+    // A new request replaces any pending one and restarts the cooldown
+    position.shares_pending_withdrawal = shares;
+    position.withdrawal_requested_at = clock.unix_timestamp;
+
+    let available_at = clock
+        .unix_timestamp
+        .checked_add(pool.withdrawal_cooldown)
+        .ok_or(NovaError::MathOverflow)?;
+
+    emit!(CapitalWithdrawalRequestedEvent {
+        provider: position.provider,
+        pool: pool.key(),
+        shares,
+        available_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Underwriter {} requested withdrawal of {} shares from pool {}",
+        position.provider,
+        shares,
+        pool.key()
+    );
+
+    Ok(())
+}
+
+/// Burn queued shares and withdraw their USDC value once the cooldown has elapsed
+pub fn withdraw_capital(ctx: Context<WithdrawCapital>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    ctx.accounts.pool.require_not_paused(PauseCategory::MEMBERSHIP)?;
+
+    // The vault of a closed pool belongs to its members' residual claims
+    require!(
+        ctx.accounts.pool.status != PoolStatus::Closed,
+        NovaError::PoolNotActive
+    );
+
+    let shares = ctx.accounts.underwriter_position.shares_pending_withdrawal;
+
+    require!(shares > 0, NovaError::NoPendingWithdrawal);
+
+    let available_at = ctx
+        .accounts
+        .underwriter_position
+        .withdrawal_requested_at
+        .checked_add(ctx.accounts.pool.withdrawal_cooldown)
+        .ok_or(NovaError::MathOverflow)?;
+    require!(
+        clock.unix_timestamp >= available_at,
+        NovaError::WithdrawalCooldownActive
+    );
+
+    // Capital cannot leave while claims it may have to cover are being decided
+    require!(
+        ctx.accounts.pool.claims_under_validation == 0,
+        NovaError::CapitalLocked
+    );
+
+    let nav = ctx.accounts.pool.net_asset_value(ctx.accounts.pool_vault.amount)?;
+    let effective_supply = ctx
+        .accounts
+        .pool
+        .effective_share_supply(ctx.accounts.share_mint.supply)?;
+    let amount = calculate_withdrawal_amount(shares, nav, effective_supply)?;
+
+    // Funds deposited to yield must be pulled back before they can be withdrawn
+    require!(
+        ctx.accounts.pool_vault.amount >= amount,
+        NovaError::InsufficientPoolFunds
+    );
+
+    // Burn shares from provider
+    let burn_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Burn {
+            mint: ctx.accounts.share_mint.to_account_info(),
+            from: ctx.accounts.provider_share_account.to_account_info(),
+            authority: ctx.accounts.provider.to_account_info(),
+        },
+    );
+    token::burn(burn_ctx, shares)?;
+
+    // Transfer USDC from pool vault to provider, signed by the pool PDA
+    let seeds = ctx.accounts.pool.signer_seeds();
+    let signer = &[&seeds[..]];
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.pool_vault.to_account_info(),
+            to: ctx.accounts.provider_token_account.to_account_info(),
+            authority: ctx.accounts.pool.to_account_info(),
+        },
+        signer,
+    );
+    token::transfer(transfer_ctx, amount)?;
+
+    let position = &mut ctx.accounts.underwriter_position;
+    position.shares_pending_withdrawal = 0;
+    position.withdrawal_requested_at = 0;
+    position.capital_withdrawn = position
+        .capital_withdrawn
+        .checked_add(amount)
+        .ok_or(NovaError::MathOverflow)?;
+
+    let pool = &mut ctx.accounts.pool;
+    pool.total_pooled = pool.total_pooled.saturating_sub(amount);
+
+    emit!(CapitalWithdrawnEvent {
+        provider: position.provider,
+        pool: pool.key(),
+        shares_burned: shares,
+        amount,
+        net_asset_value: nav,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Underwriter {} withdrew {} USDC from pool {} for {} shares",
+        position.provider,
+        amount,
+        pool.key(),
+        shares
+    );
+
+    Ok(())
+}
+
+/// Shares minted for a deposit at the current NAV, given the effective share
+/// supply including reserved shares (1:1 when nothing backs any shares yet).
+/// While NAV is zero with shares outstanding, the pool's liabilities exceed its
+/// assets and new capital would only cover the shortfall, so deposits are refused
+/// until premiums or recovered funds bring NAV back above zero.
+pub fn calculate_shares_for_deposit(amount: u64, nav: u64, share_supply: u64) -> Result<u64> {
+    if share_supply == 0 {
+        return Ok(amount);
+    }
+
+    require!(nav > 0, NovaError::UnbackedShares);
+
+    let shares = (amount as u128)
+        .checked_mul(share_supply as u128)
+        .ok_or(NovaError::MathOverflow)?
+        .checked_div(nav as u128)
+        .ok_or(NovaError::MathOverflow)?;
+    let shares = u64::try_from(shares).map_err(|_| NovaError::MathOverflow)?;
+
+    require!(shares > 0, NovaError::InvalidShareAmount);
+
+    Ok(shares)
+}
+
+/// USDC value of burning `shares` at the current NAV
+pub fn calculate_withdrawal_amount(shares: u64, nav: u64, share_supply: u64) -> Result<u64> {
+    require!(
+        share_supply > 0 && shares <= share_supply,
+        NovaError::InvalidShareAmount
+    );
+
+    let amount = (shares as u128)
+        .checked_mul(nav as u128)
+        .ok_or(NovaError::MathOverflow)?
+        .checked_div(share_supply as u128)
+        .ok_or(NovaError::MathOverflow)?;
+
+    Ok(u64::try_from(amount).map_err(|_| NovaError::MathOverflow)?)
+}
+
+// ============================================================================
+// Account Validation Contexts
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct InitializeShareMint<'info> {
+    #[account(
+        mut,
+        constraint = pool.authority == authority.key() @ NovaError::Unauthorized
+    )]
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    #[account(
+        init,
+        payer = authority,
+        mint::decimals = usdc_mint.decimals,
+        mint::authority = pool,
+        seeds = [b"share_mint", pool.key().as_ref()],
+        bump
+    )]
+    pub share_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        constraint = pool_vault.key() == pool.vault @ NovaError::Unauthorized
+    )]
+    pub pool_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        constraint = usdc_mint.key() == pool_vault.mint @ NovaError::InvalidPremiumAmount
+    )]
+    pub usdc_mint: Box<Account<'info, Mint>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct DepositCapital<'info> {
+    #[account(mut)]
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    #[account(
+        init_if_needed,
+        payer = provider,
+        space = 8 + UnderwriterPosition::INIT_SPACE,
+        seeds = [b"underwriter", provider.key().as_ref(), pool.key().as_ref()],
+        bump
+    )]
+    pub underwriter_position: Box<Account<'info, UnderwriterPosition>>,
+
+    #[account(
+        mut,
+        constraint = pool.share_mint == Some(share_mint.key()) @ NovaError::ShareMintNotInitialized
+    )]
+    pub share_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = pool_vault.key() == pool.vault @ NovaError::Unauthorized
+    )]
+    pub pool_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = provider_token_account.owner == provider.key() @ NovaError::Unauthorized,
+        constraint = provider_token_account.mint == pool_vault.mint @ NovaError::InvalidPremiumAmount
+    )]
+    pub provider_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = provider_share_account.owner == provider.key() @ NovaError::Unauthorized,
+        constraint = provider_share_account.mint == share_mint.key() @ NovaError::InvalidShareAmount
+    )]
+    pub provider_share_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct RequestCapitalWithdrawal<'info> {
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    #[account(
+        mut,
+        seeds = [b"underwriter", provider.key().as_ref(), pool.key().as_ref()],
+        bump = underwriter_position.bump,
+        constraint = underwriter_position.pool == pool.key() @ NovaError::Unauthorized
+    )]
+    pub underwriter_position: Box<Account<'info, UnderwriterPosition>>,
+
+    #[account(
+        constraint = provider_share_account.owner == provider.key() @ NovaError::Unauthorized,
+        constraint = pool.share_mint == Some(provider_share_account.mint) @ NovaError::ShareMintNotInitialized
+    )]
+    pub provider_share_account: Box<Account<'info, TokenAccount>>,
+
+    pub provider: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawCapital<'info> {
+    #[account(mut)]
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    #[account(
+        mut,
+        seeds = [b"underwriter", provider.key().as_ref(), pool.key().as_ref()],
+        bump = underwriter_position.bump,
+        constraint = underwriter_position.pool == pool.key() @ NovaError::Unauthorized
+    )]
+    pub underwriter_position: Box<Account<'info, UnderwriterPosition>>,
+
+    #[account(
+        mut,
+        constraint = pool.share_mint == Some(share_mint.key()) @ NovaError::ShareMintNotInitialized
+    )]
+    pub share_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = pool_vault.key() == pool.vault @ NovaError::Unauthorized
+    )]
+    pub pool_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = provider_token_account.owner == provider.key() @ NovaError::Unauthorized,
+        constraint = provider_token_account.mint == pool_vault.mint @ NovaError::InvalidPremiumAmount
+    )]
+    pub provider_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = provider_share_account.owner == provider.key() @ NovaError::Unauthorized,
+        constraint = provider_share_account.mint == share_mint.key() @ NovaError::InvalidShareAmount
+    )]
+    pub provider_share_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+#[event]
+pub struct ShareMintInitializedEvent {
+    pub pool: Pubkey,
+    pub share_mint: Pubkey,
+    pub withdrawal_cooldown: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CapitalDepositedEvent {
+    pub provider: Pubkey,
+    pub pool: Pubkey,
+    pub amount: u64,
+    pub shares_minted: u64,
+    pub net_asset_value: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CapitalWithdrawalRequestedEvent {
+    pub provider: Pubkey,
+    pub pool: Pubkey,
+    pub shares: u64,
+    pub available_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CapitalWithdrawnEvent {
+    pub provider: Pubkey,
+    pub pool: Pubkey,
+    pub shares_burned: u64,
+    pub amount: u64,
+    pub net_asset_value: u64,
+    pub timestamp: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_deposit_mints_one_to_one() {
+        assert_eq!(calculate_shares_for_deposit(1_000, 0, 0).unwrap(), 1_000);
+    }
+
+    #[test]
+    fn deposit_priced_at_nav() {
+        // NAV doubled since the existing shares were minted
+        assert_eq!(calculate_shares_for_deposit(1_000, 2_000, 1_000).unwrap(), 500);
+    }
+
+    #[test]
+    fn deposit_rejected_when_shares_have_no_backing() {
+        assert_eq!(
+            calculate_shares_for_deposit(1_000, 0, 1_000).unwrap_err(),
+            NovaError::UnbackedShares.into()
+        );
+    }
+
+    #[test]
+    fn deposit_too_small_for_a_share_rejected() {
+        assert!(calculate_shares_for_deposit(1, 1_000, 1).is_err());
+    }
+
+    #[test]
+    fn withdrawal_pays_pro_rata_nav() {
+        assert_eq!(calculate_withdrawal_amount(250, 3_000, 1_000).unwrap(), 750);
+        assert_eq!(calculate_withdrawal_amount(1_000, 3_000, 1_000).unwrap(), 3_000);
+    }
+
+    #[test]
+    fn withdrawal_rounds_down() {
+        assert_eq!(calculate_withdrawal_amount(1, 10, 3).unwrap(), 3);
+    }
+
+    #[test]
+    fn withdrawal_of_more_than_supply_rejected() {
+        assert!(calculate_withdrawal_amount(1_001, 3_000, 1_000).is_err());
+        assert!(calculate_withdrawal_amount(1, 3_000, 0).is_err());
+    }
+
+    #[test]
+    fn deposit_against_reserved_shares_cannot_claim_existing_funds() {
+        // 1_000 USDC of member premiums backed by 1_000 reserved shares
+        let shares = calculate_shares_for_deposit(1_000, 1_000, 1_000).unwrap();
+        assert_eq!(shares, 1_000);
+        // The underwriter redeems only what they deposited
+        let amount = calculate_withdrawal_amount(shares, 2_000, 1_000 + shares).unwrap();
+        assert_eq!(amount, 1_000);
+    }
+}
@@ -12,16 +12,26 @@ pub fn submit_claim(
     description: String,
 ) -> Result<()> {
     let claim = &mut ctx.accounts.claim_request;
-    let user_coverage = &ctx.accounts.user_coverage;
+    let user_coverage = &mut ctx.accounts.user_coverage;
+    let claimant_nonce = &mut ctx.accounts.claimant_nonce;
     let pool = &mut ctx.accounts.pool;
     let clock = Clock::get()?;
 
+    pool.require_not_paused(PauseCategory::CLAIMS)?;
+    pool.require_active()?;
+
     // Verify user has active coverage
     require!(
         user_coverage.coverage_active,
         NovaError::InactiveCoverage
     );
 
+    // Coverage past its grace period is overdue even before the lapse crank runs
+    require!(
+        !user_coverage.is_overdue(clock.unix_timestamp, pool.grace_period),
+        NovaError::PremiumOverdue
+    );
+
     // Verify the coverage belongs to this user and pool
     require!(
         user_coverage.user == ctx.accounts.claimant.key(),
@@ -37,10 +47,6 @@ pub fn submit_claim(
         amount_requested > 0,
         NovaError::InvalidCoverageAmount
     );
-    require!(
-        amount_requested <= user_coverage.coverage_amount,
-        NovaError::ExcessiveClaimAmount
-    );
 
     // Validate claim period - claim must be for recent incident
     let time_since_incident = clock.unix_timestamp.saturating_sub(incident_timestamp);
@@ -59,6 +65,47 @@ pub fn submit_claim(
         NovaError::ClaimPeriodExpired
     );
 
+    // Incident must fall inside a period the member actually paid for
+    require!(
+        user_coverage.covers_incident(incident_timestamp),
+        NovaError::IncidentNotCovered
+    );
+
+    // Incidents during the waiting period after coverage starts are excluded
+    let waiting_period_end = user_coverage
+        .covered_from
+        .checked_add(pool.waiting_period_for(incident_type))
+        .ok_or(NovaError::MathOverflow)?;
+    require!(
+        incident_timestamp >= waiting_period_end,
+        NovaError::WaitingPeriodNotElapsed
+    );
+
+    // Coverage increases are subject to their own waiting period
+    let covered_amount = user_coverage
+        .coverage_for_incident(incident_timestamp, pool.waiting_period_for(incident_type));
+    require!(
+        amount_requested <= covered_amount,
+        NovaError::ExcessiveClaimAmount
+    );
+
+    // Enforce the member's annual claim count and aggregate limit
+    user_coverage.roll_policy_year(clock.unix_timestamp);
+    if pool.max_claims_per_year > 0 {
+        require!(
+            user_coverage.claims_made < pool.max_claims_per_year,
+            NovaError::AnnualClaimCountExceeded
+        );
+    }
+    let claimed_this_year = user_coverage
+        .claimed_this_year
+        .checked_add(amount_requested)
+        .ok_or(NovaError::MathOverflow)?;
+    require!(
+        claimed_this_year <= pool.annual_limit_for(user_coverage.coverage_amount),
+        NovaError::AnnualLimitExceeded
+    );
+
     // Validate description length
     require!(
         description.len() <= 100,
@@ -86,19 +133,69 @@ pub fn submit_claim(
     claim.vrf_result = None;
     claim.created_at = clock.unix_timestamp;
     claim.resolved_at = None;
+    claim.approved_amount = 0;
+    claim.slash_reward_pool = 0;
     claim.payout_amount = None;
+    claim.deductible_applied = 0;
+    claim.coinsurance_applied = 0;
+    claim.annual_limit_applied = 0;
+    claim.sequence = pool.claim_sequence;
+    claim.claimant_nonce = claimant_nonce.next_nonce;
+    claim.appealed = false;
+    claim.appeal_bond = 0;
+    claim.appeal_overturned = false;
+    claim.original_validators = Vec::new();
+    claim.original_majority = Vec::new();
+    claim.voting_deadline = 0;
+    claim.commitments = Vec::new();
+    claim.reveal_deadline = 0;
+    claim.recused = Vec::new();
     claim.bump = *ctx.bumps.get("claim_request").unwrap();
 
+    // The nonce counter persists across memberships; it is only created on the first claim
+    claimant_nonce.pool = pool_key;
+    claimant_nonce.claimant = claimant_key;
+    claimant_nonce.bump = *ctx.bumps.get("claimant_nonce").unwrap();
+
+    // Record the claim under the member's nonce for enumeration
+    let claim_index = &mut ctx.accounts.claim_index;
+    claim_index.pool = pool_key;
+    claim_index.claimant = claimant_key;
+    claim_index.nonce = claimant_nonce.next_nonce;
+    claim_index.claim = claim_key;
+    claim_index.sequence = pool.claim_sequence;
+    claim_index.bump = *ctx.bumps.get("claim_index").unwrap();
+
+    pool.claim_sequence = pool
+        .claim_sequence
+        .checked_add(1)
+        .ok_or(NovaError::MathOverflow)?;
+    claimant_nonce.next_nonce = claimant_nonce
+        .next_nonce
+        .checked_add(1)
+        .ok_or(NovaError::MathOverflow)?;
+
     // Update pool active claims counter
     pool.active_claims = pool
         .active_claims
         .checked_add(1)
         .ok_or(NovaError::InvalidCoverageAmount)?;
+    user_coverage.open_claims = user_coverage
+        .open_claims
+        .checked_add(1)
+        .ok_or(NovaError::MathOverflow)?;
+    user_coverage.claims_made = user_coverage
+        .claims_made
+        .checked_add(1)
+        .ok_or(NovaError::MathOverflow)?;
+    user_coverage.claimed_this_year = claimed_this_year;
 
     emit!(ClaimSubmittedEvent {
         claim_id: claim_key,
         claimant: claimant_key,
         pool: pool_key,
+        sequence: claim.sequence,
+        claimant_nonce: claim.claimant_nonce,
         amount_requested,
         incident_type,
         incident_timestamp,
@@ -116,6 +213,77 @@ pub fn submit_claim(
     Ok(())
 }
 
+/// Withdraw a claim before any validator has voted on it, closing the claim
+/// account back to the claimant
+pub fn withdraw_claim(ctx: Context<WithdrawClaim>) -> Result<()> {
+    let claim = &mut ctx.accounts.claim_request;
+    let user_coverage = &mut ctx.accounts.user_coverage;
+    let pool = &mut ctx.accounts.pool;
+    let clock = Clock::get()?;
+
+    require!(
+        claim.status == ClaimStatus::Pending || claim.status == ClaimStatus::UnderValidation,
+        NovaError::ClaimNotWithdrawable
+    );
+    require!(
+        claim.validations.is_empty() && claim.commitments.is_empty(),
+        NovaError::ClaimNotWithdrawable
+    );
+
+    if claim.status == ClaimStatus::UnderValidation {
+        pool.claims_under_validation = pool.claims_under_validation.saturating_sub(1);
+    }
+
+    // Assigned validators are freed from the claim; the registry is required to record it
+    if !claim.validators_assigned.is_empty() {
+        let validator_registry = ctx
+            .accounts
+            .validator_registry
+            .as_mut()
+            .ok_or(NovaError::ClaimNotWithdrawable)?;
+        validator_registry.release_assignments(&claim.validators_assigned);
+    }
+    claim.status = ClaimStatus::Withdrawn;
+    claim.resolved_at = Some(clock.unix_timestamp);
+
+    pool.active_claims = pool.active_claims.saturating_sub(1);
+    user_coverage.open_claims = user_coverage.open_claims.saturating_sub(1);
+
+    // A withdrawn claim does not count toward the member's annual limits
+    if claim.created_at >= user_coverage.policy_year_start {
+        user_coverage.claims_made = user_coverage.claims_made.saturating_sub(1);
+        user_coverage.claimed_this_year = user_coverage
+            .claimed_this_year
+            .saturating_sub(claim.amount_requested);
+    }
+
+    // Pools with VRF state must pass it so the claim leaves the pending list
+    if pool.vrf_initialized {
+        let vrf_state = ctx
+            .accounts
+            .vrf_state
+            .as_mut()
+            .ok_or(NovaError::ClaimNotWithdrawable)?;
+        let claim_key = claim.key();
+        vrf_state.pending_claims.retain(|c| *c != claim_key);
+    }
+
+    emit!(ClaimWithdrawnEvent {
+        claim_id: claim.key(),
+        claimant: claim.claimant,
+        pool: pool.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Claim {} withdrawn by {}",
+        claim.key(),
+        claim.claimant
+    );
+
+    Ok(())
+}
+
 // ============================================================================
 // Account Validation Contexts
 // ============================================================================
@@ -128,18 +296,42 @@ pub struct SubmitClaim<'info> {
         space = 8 + ClaimRequest::INIT_SPACE,
         seeds = [
             b"claim",
-            claimant.key().as_ref(),
             pool.key().as_ref(),
-            &clock.unix_timestamp.to_le_bytes()
+            &pool.claim_sequence.to_le_bytes()
         ],
         bump
     )]
     pub claim_request: Box<Account<'info, ClaimRequest>>,
 
+    /// Created on the member's first claim in the pool and kept across memberships
+    #[account(
+        init_if_needed,
+        payer = claimant,
+        space = 8 + ClaimantNonce::INIT_SPACE,
+        seeds = [b"claimant_nonce", pool.key().as_ref(), claimant.key().as_ref()],
+        bump
+    )]
+    pub claimant_nonce: Box<Account<'info, ClaimantNonce>>,
+
+    #[account(
+        init,
+        payer = claimant,
+        space = 8 + ClaimIndex::INIT_SPACE,
+        seeds = [
+            b"claim_index",
+            pool.key().as_ref(),
+            claimant.key().as_ref(),
+            &claimant_nonce.next_nonce.to_le_bytes()
+        ],
+        bump
+    )]
+    pub claim_index: Box<Account<'info, ClaimIndex>>,
+
     #[account(mut)]
     pub pool: Box<Account<'info, InsurancePool>>,
 
     #[account(
+        mut,
         seeds = [b"coverage", claimant.key().as_ref(), pool.key().as_ref()],
         bump = user_coverage.bump,
         constraint = user_coverage.pool == pool.key() @ NovaError::InactiveCoverage,
@@ -151,9 +343,65 @@ pub struct SubmitClaim<'info> {
     pub claimant: Signer<'info>,
 
     pub system_program: Program<'info, System>,
-    
-    /// Clock sysvar for timestamp
-    pub clock: Sysvar<'info, Clock>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawClaim<'info> {
+    #[account(
+        mut,
+        close = claimant,
+        seeds = [
+            b"claim",
+            pool.key().as_ref(),
+            &claim_request.sequence.to_le_bytes()
+        ],
+        bump = claim_request.bump,
+        constraint = claim_request.claimant == claimant.key() @ NovaError::Unauthorized
+    )]
+    pub claim_request: Box<Account<'info, ClaimRequest>>,
+
+    #[account(
+        mut,
+        close = claimant,
+        seeds = [
+            b"claim_index",
+            pool.key().as_ref(),
+            claimant.key().as_ref(),
+            &claim_request.claimant_nonce.to_le_bytes()
+        ],
+        bump = claim_index.bump
+    )]
+    pub claim_index: Box<Account<'info, ClaimIndex>>,
+
+    #[account(mut)]
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    #[account(
+        mut,
+        seeds = [b"coverage", claimant.key().as_ref(), pool.key().as_ref()],
+        bump = user_coverage.bump,
+        constraint = user_coverage.pool == pool.key() @ NovaError::InactiveCoverage
+    )]
+    pub user_coverage: Box<Account<'info, UserCoverage>>,
+
+    /// Pool's VRF state; required once initialized so the claim is dropped from its pending list
+    #[account(
+        mut,
+        seeds = [b"vrf_state", pool.key().as_ref()],
+        bump = vrf_state.bump
+    )]
+    pub vrf_state: Option<Box<Account<'info, VrfState>>>,
+
+    /// Pool's validator registry; required when validators have been assigned to the claim
+    #[account(
+        mut,
+        seeds = [b"validator_registry", pool.key().as_ref()],
+        bump = validator_registry.bump
+    )]
+    pub validator_registry: Option<Box<Account<'info, ValidatorRegistry>>>,
+
+    #[account(mut)]
+    pub claimant: Signer<'info>,
 }
 
 // ============================================================================
@@ -165,8 +413,18 @@ pub struct ClaimSubmittedEvent {
     pub claim_id: Pubkey,
     pub claimant: Pubkey,
     pub pool: Pubkey,
+    pub sequence: u64,
+    pub claimant_nonce: u64,
     pub amount_requested: u64,
     pub incident_type: IncidentType,
     pub incident_timestamp: i64,
     pub timestamp: i64,
 }
+
+#[event]
+pub struct ClaimWithdrawnEvent {
+    pub claim_id: Pubkey,
+    pub claimant: Pubkey,
+    pub pool: Pubkey,
+    pub timestamp: i64,
+}
@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
 use crate::errors::NovaError;
+use crate::instructions::vrf_integration::select_random_validators;
 use crate::state::*;
 
 /// Fulfill VRF callback for validator selection
@@ -12,16 +13,28 @@ pub fn fulfill_validator_selection(
 ) -> Result<()> {
     let vrf_state = &mut ctx.accounts.vrf_state;
     let claim = &mut ctx.accounts.claim_request;
-    let validator_registry = &ctx.accounts.validator_registry;
-    let pool = &ctx.accounts.pool;
+    let validator_registry = &mut ctx.accounts.validator_registry;
+    let pool = &mut ctx.accounts.pool;
     let clock = Clock::get()?;
 
+    pool.require_not_paused(PauseCategory::VALIDATION)?;
+
     // Verify VRF state belongs to pool
     require!(
         vrf_state.pool == pool.key(),
         NovaError::InvalidPoolType
     );
 
+    // Only pending claims that still need validators can be fulfilled
+    require!(
+        claim.status == ClaimStatus::Pending,
+        NovaError::ClaimPeriodExpired
+    );
+    require!(
+        claim.validators_assigned.is_empty(),
+        NovaError::DuplicateValidation
+    );
+
     // Store randomness result
     vrf_state.last_randomness = Some(randomness);
     vrf_state.last_timestamp = clock.unix_timestamp;
@@ -30,52 +43,29 @@ pub fn fulfill_validator_selection(
         .checked_add(1)
         .ok_or(NovaError::InvalidCoverageAmount)?;
 
-    // Get number of validators to select (min_validators from pool)
-    let num_validators = pool.min_validators as usize;
-    let available_validators = &validator_registry.validators;
-
-    // Ensure we have enough validators
-    require!(
-        available_validators.len() >= num_validators,
-        NovaError::InsufficientValidators
-    );
-
-    // Use randomness to select validators
-    let mut selected_validators = Vec::new();
-    let mut used_indices = Vec::new();
-
-    // Convert randomness to selection indices
-    for i in 0..num_validators {
-        // Use different bytes of randomness for each selection
-        let random_bytes = [
-            randomness[i * 4],
-            randomness[i * 4 + 1],
-            randomness[i * 4 + 2],
-            randomness[i * 4 + 3],
-        ];
-        let random_value = u32::from_le_bytes(random_bytes);
-        
-        // Find an unused validator index
-        let mut attempts = 0;
-        loop {
-            let index = ((random_value as usize + attempts) % available_validators.len()) as usize;
-            
-            if !used_indices.contains(&index) {
-                used_indices.push(index);
-                selected_validators.push(available_validators[index]);
-                break;
-            }
-            
-            attempts += 1;
-            if attempts >= available_validators.len() {
-                return Err(NovaError::InsufficientValidators.into());
-            }
-        }
-    }
+    // Select min_validators validators, weighted by stake and reputation
+    let selected_validators = select_random_validators(
+        &randomness,
+        validator_registry,
+        pool,
+        &claim.conflicted_validators(),
+        pool.min_validators as usize,
+    )?;
 
     // Assign validators to claim
+    pool.claims_under_validation = pool
+        .claims_under_validation
+        .checked_add(1)
+        .ok_or(NovaError::MathOverflow)?;
+    validator_registry.record_assignments(&selected_validators)?;
     claim.validators_assigned = selected_validators;
     claim.vrf_result = Some(randomness);
+    claim.commitments = Vec::new();
+    claim.reveal_deadline = 0;
+    claim.voting_deadline = clock
+        .unix_timestamp
+        .checked_add(pool.voting_period)
+        .ok_or(NovaError::MathOverflow)?;
     claim.status = ClaimStatus::UnderValidation;
 
     // Remove claim from pending queue
@@ -138,6 +128,8 @@ pub fn distribute_claims(
     let pool = &mut ctx.accounts.pool;
     let clock = Clock::get()?;
 
+    pool.require_not_paused(PauseCategory::DISTRIBUTION)?;
+
     // Update available funds from current pool balance
     queue.available_funds = pool.total_pooled;
 
@@ -199,7 +191,7 @@ pub fn distribute_claims(
             // Find next unselected claim
             let mut attempts = 0;
             loop {
-                let index = ((random_value as usize + attempts) % total_claims) as usize;
+                let index = (random_value as usize + attempts) % total_claims;
                 
                 if !selected_indices.contains(&index) {
                     selected_indices.push(index);
@@ -257,6 +249,8 @@ pub fn payout_claim(ctx: Context<PayoutClaim>) -> Result<()> {
     let queue = &mut ctx.accounts.distribution_queue;
     let clock = Clock::get()?;
 
+    pool.require_not_paused(PauseCategory::DISTRIBUTION)?;
+
     // Verify claim is approved and selected for payout
     require!(
         claim.status == ClaimStatus::Approved,
@@ -268,8 +262,10 @@ pub fn payout_claim(ctx: Context<PayoutClaim>) -> Result<()> {
         NovaError::UnauthorizedValidator
     );
 
-    // Calculate payout amount
-    let payout_amount = claim.amount_requested.min(claim.payout_amount.unwrap_or(claim.amount_requested));
+    // The annual aggregate was applied when the claim was approved
+    let payout_amount = claim.payout_amount.unwrap_or(claim.amount_requested);
+    let claimant_coverage = &mut ctx.accounts.claimant_coverage;
+    claimant_coverage.roll_policy_year(clock.unix_timestamp);
 
     // Verify pool has sufficient funds
     require!(
@@ -278,14 +274,8 @@ pub fn payout_claim(ctx: Context<PayoutClaim>) -> Result<()> {
     );
 
     // Transfer USDC from pool vault to claimant
-    // Extract values needed for seeds before creating CPI context
-    let pool_key = pool.key();
-    let pool_bump = pool.bump;
-    let seeds = &[
-        b"vault",
-        pool_key.as_ref(),
-        &[pool_bump],
-    ];
+    // The vault is owned by the pool PDA, so the pool signs the transfer
+    let seeds = pool.signer_seeds();
     let signer = &[&seeds[..]];
 
     let transfer_ctx = CpiContext::new_with_signer(
@@ -293,7 +283,7 @@ pub fn payout_claim(ctx: Context<PayoutClaim>) -> Result<()> {
         Transfer {
             from: ctx.accounts.pool_vault.to_account_info(),
             to: ctx.accounts.claimant_token_account.to_account_info(),
-            authority: ctx.accounts.pool_vault.to_account_info(),
+            authority: pool.to_account_info(),
         },
         signer,
     );
@@ -302,10 +292,18 @@ pub fn payout_claim(ctx: Context<PayoutClaim>) -> Result<()> {
     // Update pool and claim state
     pool.total_pooled = pool.total_pooled.saturating_sub(payout_amount);
     pool.active_claims = pool.active_claims.saturating_sub(1);
+    pool.approved_unpaid_claims = pool
+        .approved_unpaid_claims
+        .saturating_sub(payout_amount);
     
     claim.status = ClaimStatus::Distributed;
     claim.resolved_at = Some(clock.unix_timestamp);
-    claim.payout_amount = Some(payout_amount);
+
+    claimant_coverage.open_claims = claimant_coverage.open_claims.saturating_sub(1);
+    claimant_coverage.paid_this_year = claimant_coverage
+        .paid_this_year
+        .checked_add(payout_amount)
+        .ok_or(NovaError::MathOverflow)?;
 
     // Remove from distribution queue
     if let Some(pos) = queue.pending_claims.iter().position(|&c| c == claim.key()) {
@@ -323,6 +321,9 @@ pub fn payout_claim(ctx: Context<PayoutClaim>) -> Result<()> {
         claim_id: claim.key(),
         claimant: claim.claimant,
         pool: pool.key(),
+        amount_requested: claim.amount_requested,
+        deductible_applied: claim.deductible_applied,
+        coinsurance_applied: claim.coinsurance_applied,
         amount: payout_amount,
         timestamp: clock.unix_timestamp,
     });
@@ -344,6 +345,8 @@ pub fn add_to_distribution_queue(
     let queue = &mut ctx.accounts.distribution_queue;
     let claim = &ctx.accounts.claim_request;
 
+    ctx.accounts.pool.require_not_paused(PauseCategory::DISTRIBUTION)?;
+
     // Verify claim is approved
     require!(
         claim.status == ClaimStatus::Approved,
@@ -364,7 +367,7 @@ pub fn add_to_distribution_queue(
         .ok_or(NovaError::InvalidCoverageAmount)?;
     queue.total_requested_amount = queue
         .total_requested_amount
-        .checked_add(claim.amount_requested)
+        .checked_add(claim.payout_amount.unwrap_or(claim.amount_requested))
         .ok_or(NovaError::InvalidCoverageAmount)?;
 
     msg!(
@@ -385,23 +388,37 @@ pub fn add_to_distribution_queue(
 pub struct FulfillValidatorSelection<'info> {
     #[account(
         mut,
-        seeds = [b"vrf", pool.key().as_ref()],
+        seeds = [b"vrf_state", pool.key().as_ref()],
         bump = vrf_state.bump
     )]
     pub vrf_state: Box<Account<'info, VrfState>>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [
+            b"claim",
+            pool.key().as_ref(),
+            &claim_request.sequence.to_le_bytes()
+        ],
+        bump = claim_request.bump
+    )]
     pub claim_request: Box<Account<'info, ClaimRequest>>,
 
     #[account(
-        seeds = [b"registry", pool.key().as_ref()],
+        mut,
+        seeds = [b"validator_registry", pool.key().as_ref()],
         bump = validator_registry.bump
     )]
     pub validator_registry: Box<Account<'info, ValidatorRegistry>>,
 
+    #[account(mut)]
     pub pool: Box<Account<'info, InsurancePool>>,
 
-    #[account(mut)]
+    /// Only the VRF authority may deliver randomness
+    #[account(
+        mut,
+        constraint = authority.key() == vrf_state.authority @ NovaError::Unauthorized
+    )]
     pub authority: Signer<'info>,
 }
 
@@ -442,7 +459,15 @@ pub struct DistributeClaims<'info> {
 
 #[derive(Accounts)]
 pub struct PayoutClaim<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [
+            b"claim",
+            pool.key().as_ref(),
+            &claim_request.sequence.to_le_bytes()
+        ],
+        bump = claim_request.bump
+    )]
     pub claim_request: Box<Account<'info, ClaimRequest>>,
 
     #[account(mut)]
@@ -462,10 +487,26 @@ pub struct PayoutClaim<'info> {
     )]
     pub pool_vault: Box<Account<'info, TokenAccount>>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"coverage", claim_request.claimant.as_ref(), pool.key().as_ref()],
+        bump = claimant_coverage.bump
+    )]
+    pub claimant_coverage: Box<Account<'info, UserCoverage>>,
+
+    #[account(
+        mut,
+        constraint = claimant_token_account.owner == claim_request.claimant @ NovaError::Unauthorized,
+        constraint = claimant_token_account.mint == pool_vault.mint @ NovaError::InvalidPremiumAmount
+    )]
     pub claimant_token_account: Box<Account<'info, TokenAccount>>,
 
-    #[account(mut)]
+    /// Either the claimant or the pool authority may trigger the payout
+    #[account(
+        mut,
+        constraint = authority.key() == claim_request.claimant
+            || authority.key() == pool.authority @ NovaError::Unauthorized
+    )]
     pub authority: Signer<'info>,
 
     pub token_program: Program<'info, Token>,
@@ -480,6 +521,14 @@ pub struct AddToDistributionQueue<'info> {
     )]
     pub distribution_queue: Box<Account<'info, DistributionQueue>>,
 
+    #[account(
+        seeds = [
+            b"claim",
+            pool.key().as_ref(),
+            &claim_request.sequence.to_le_bytes()
+        ],
+        bump = claim_request.bump
+    )]
     pub claim_request: Box<Account<'info, ClaimRequest>>,
 
     pub pool: Box<Account<'info, InsurancePool>>,
@@ -522,6 +571,9 @@ pub struct ClaimPaidOutEvent {
     pub claim_id: Pubkey,
     pub claimant: Pubkey,
     pub pool: Pubkey,
+    pub amount_requested: u64,
+    pub deductible_applied: u64,
+    pub coinsurance_applied: u64,
     pub amount: u64,
     pub timestamp: i64,
 }
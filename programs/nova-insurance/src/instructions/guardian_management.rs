@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::state::*;
+
+/// Set or clear the pool's emergency guardian
+pub fn set_guardian(ctx: Context<SetGuardian>, guardian: Option<Pubkey>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let clock = Clock::get()?;
+
+    let previous_guardian = pool.guardian;
+    pool.guardian = guardian;
+
+    emit!(GuardianUpdatedEvent {
+        pool: pool.key(),
+        previous_guardian,
+        guardian,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Guardian for pool {} set to {:?}", pool.key(), guardian);
+
+    Ok(())
+}
+
+/// Pause one or more instruction categories
+pub fn pause_categories(ctx: Context<GuardianAction>, categories: u8) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let clock = Clock::get()?;
+
+    require!(
+        categories != 0 && categories & !PauseCategory::ALL == 0,
+        NovaError::InvalidPauseCategories
+    );
+
+    pool.paused_categories |= categories;
+
+    emit!(PoolPausedEvent {
+        pool: pool.key(),
+        guardian: ctx.accounts.guardian.key(),
+        categories,
+        paused_categories: pool.paused_categories,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Guardian paused categories {:#07b} on pool {}",
+        categories,
+        pool.key()
+    );
+
+    Ok(())
+}
+
+/// Unpause one or more instruction categories
+pub fn unpause_categories(ctx: Context<GuardianAction>, categories: u8) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let clock = Clock::get()?;
+
+    require!(
+        categories != 0 && categories & !PauseCategory::ALL == 0,
+        NovaError::InvalidPauseCategories
+    );
+
+    pool.paused_categories &= !categories;
+
+    emit!(PoolUnpausedEvent {
+        pool: pool.key(),
+        guardian: ctx.accounts.guardian.key(),
+        categories,
+        paused_categories: pool.paused_categories,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Guardian unpaused categories {:#07b} on pool {}",
+        categories,
+        pool.key()
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// Account Validation Contexts
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct SetGuardian<'info> {
+    #[account(
+        mut,
+        constraint = pool.authority == authority.key() @ NovaError::Unauthorized
+    )]
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct GuardianAction<'info> {
+    #[account(
+        mut,
+        constraint = pool.guardian == Some(guardian.key()) @ NovaError::Unauthorized
+    )]
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    pub guardian: Signer<'info>,
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+#[event]
+pub struct GuardianUpdatedEvent {
+    pub pool: Pubkey,
+    pub previous_guardian: Option<Pubkey>,
+    pub guardian: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PoolPausedEvent {
+    pub pool: Pubkey,
+    pub guardian: Pubkey,
+    pub categories: u8,
+    pub paused_categories: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PoolUnpausedEvent {
+    pub pool: Pubkey,
+    pub guardian: Pubkey,
+    pub categories: u8,
+    pub paused_categories: u8,
+    pub timestamp: i64,
+}
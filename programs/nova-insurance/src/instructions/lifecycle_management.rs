@@ -0,0 +1,402 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, Transfer};
+
+use crate::errors::*;
+use crate::state::*;
+
+/// Pause or resume a pool (Active <-> Paused)
+pub fn set_pool_status(ctx: Context<SetPoolStatus>, status: PoolStatus) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let clock = Clock::get()?;
+
+    // Wind-down and closure have their own instructions
+    let allowed = matches!(
+        (pool.status, status),
+        (PoolStatus::Active, PoolStatus::Paused) | (PoolStatus::Paused, PoolStatus::Active)
+    );
+    require!(allowed, NovaError::InvalidStatusTransition);
+
+    let previous_status = pool.status;
+    pool.status = status;
+
+    emit!(PoolStatusChangedEvent {
+        pool: pool.key(),
+        previous_status,
+        status,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Pool {} status changed from {:?} to {:?}",
+        pool.key(),
+        previous_status,
+        status
+    );
+
+    Ok(())
+}
+
+/// Begin an orderly wind-down: no new members, premiums or claims are accepted
+/// while outstanding claims settle through the distribution queue
+pub fn begin_wind_down(ctx: Context<BeginWindDown>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let clock = Clock::get()?;
+
+    require!(
+        pool.status == PoolStatus::Active || pool.status == PoolStatus::Paused,
+        NovaError::InvalidStatusTransition
+    );
+
+    let previous_status = pool.status;
+    pool.status = PoolStatus::WindingDown;
+
+    emit!(PoolStatusChangedEvent {
+        pool: pool.key(),
+        previous_status,
+        status: PoolStatus::WindingDown,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Pool {} winding down with {} active claims",
+        pool.key(),
+        pool.active_claims
+    );
+
+    Ok(())
+}
+
+/// Close a wound-down pool once all claims are settled, every rejected claim's
+/// appeal window has passed and yield funds are recalled, snapshotting the
+/// residual vault balance for pro-rata distribution to members
+pub fn finalize_wind_down(ctx: Context<FinalizeWindDown>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let clock = Clock::get()?;
+
+    require!(
+        pool.status == PoolStatus::WindingDown,
+        NovaError::InvalidStatusTransition
+    );
+    require!(pool.active_claims == 0, NovaError::OutstandingClaims);
+    let appeals_close_at = pool
+        .last_rejection_at
+        .checked_add(pool.appeal_window)
+        .ok_or(NovaError::MathOverflow)?;
+    require!(
+        clock.unix_timestamp > appeals_close_at,
+        NovaError::AppealWindowOpen
+    );
+    require!(pool.yield_deposited == 0, NovaError::YieldNotWithdrawn);
+
+    // Underwriters must redeem their shares before members split the residual
+    if let Some(share_mint_key) = pool.share_mint {
+        let share_mint = ctx
+            .accounts
+            .share_mint
+            .as_ref()
+            .ok_or(NovaError::ShareMintNotInitialized)?;
+        require!(
+            share_mint.key() == share_mint_key,
+            NovaError::ShareMintNotInitialized
+        );
+        require!(share_mint.supply == 0, NovaError::OutstandingShares);
+    }
+
+    // Appeal bonds still owed to claimants are not part of the residual
+    pool.residual_balance = ctx
+        .accounts
+        .pool_vault
+        .amount
+        .saturating_sub(pool.appeal_bonds_held);
+    pool.residual_premiums = pool.total_member_premiums;
+    pool.residual_claim_deadline = clock
+        .unix_timestamp
+        .checked_add(InsurancePool::RESIDUAL_CLAIM_WINDOW)
+        .ok_or(NovaError::MathOverflow)?;
+    pool.status = PoolStatus::Closed;
+
+    emit!(PoolStatusChangedEvent {
+        pool: pool.key(),
+        previous_status: PoolStatus::WindingDown,
+        status: PoolStatus::Closed,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Pool {} closed with {} USDC residual across {} member accounts",
+        pool.key(),
+        pool.residual_balance,
+        pool.member_accounts
+    );
+
+    Ok(())
+}
+
+/// Claim a member's pro-rata share of a closed pool's residual balance,
+/// closing their coverage account
+pub fn claim_residual(ctx: Context<ClaimResidual>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    ctx.accounts.pool.require_not_paused(PauseCategory::DISTRIBUTION)?;
+
+    let pool = &ctx.accounts.pool;
+    let premiums_paid = ctx.accounts.user_coverage.premiums_paid;
+
+    require!(pool.status == PoolStatus::Closed, NovaError::InvalidStatusTransition);
+
+    let amount = if pool.residual_premiums == 0 {
+        0
+    } else {
+        (pool.residual_balance as u128)
+            .checked_mul(premiums_paid as u128)
+            .ok_or(NovaError::MathOverflow)?
+            .checked_div(pool.residual_premiums as u128)
+            .ok_or(NovaError::MathOverflow)? as u64
+    };
+
+    if amount > 0 {
+        let seeds = pool.signer_seeds();
+        let signer = &[&seeds[..]];
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.pool_vault.to_account_info(),
+                to: ctx.accounts.user_token_account.to_account_info(),
+                authority: ctx.accounts.pool.to_account_info(),
+            },
+            signer,
+        );
+        token::transfer(transfer_ctx, amount)?;
+    }
+
+    let pool = &mut ctx.accounts.pool;
+    pool.member_accounts = pool.member_accounts.saturating_sub(1);
+    pool.total_pooled = pool.total_pooled.saturating_sub(amount);
+
+    emit!(ResidualClaimedEvent {
+        user: ctx.accounts.user.key(),
+        pool: pool.key(),
+        premiums_paid,
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "User {} claimed {} USDC residual from pool {}",
+        ctx.accounts.user.key(),
+        amount,
+        pool.key()
+    );
+
+    Ok(())
+}
+
+/// Close the vault of a closed pool once every member has claimed their share
+/// and every appeal bond is refunded, or once the residual claim deadline has
+/// passed, sweeping what is left to the authority and delisting the pool
+pub fn close_pool_vault(ctx: Context<ClosePoolVault>) -> Result<()> {
+    let clock = Clock::get()?;
+    let pool = &ctx.accounts.pool;
+
+    require!(
+        pool.status == PoolStatus::Closed,
+        NovaError::InvalidStatusTransition
+    );
+    require!(
+        (pool.member_accounts == 0 && pool.appeal_bonds_held == 0)
+            || clock.unix_timestamp > pool.residual_claim_deadline,
+        NovaError::MembersRemaining
+    );
+
+    let dust = ctx.accounts.pool_vault.amount;
+    let seeds = ctx.accounts.pool.signer_seeds();
+    let signer = &[&seeds[..]];
+
+    if dust > 0 {
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.pool_vault.to_account_info(),
+                to: ctx.accounts.authority_token_account.to_account_info(),
+                authority: ctx.accounts.pool.to_account_info(),
+            },
+            signer,
+        );
+        token::transfer(transfer_ctx, dust)?;
+    }
+
+    let close_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.pool_vault.to_account_info(),
+            destination: ctx.accounts.authority.to_account_info(),
+            authority: ctx.accounts.pool.to_account_info(),
+        },
+        signer,
+    );
+    token::close_account(close_ctx)?;
+
+    // Remove the pool from its directory
+    let pool_key = ctx.accounts.pool.key();
+    let directory = &mut ctx.accounts.pool_directory;
+    if let Some(pos) = directory.pools.iter().position(|&p| p == pool_key) {
+        directory.pools.remove(pos);
+        directory.total_pools = directory.total_pools.saturating_sub(1);
+    }
+
+    ctx.accounts.pool.total_pooled = 0;
+
+    emit!(PoolVaultClosedEvent {
+        pool: pool_key,
+        dust_swept: dust,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Vault closed for pool {}", pool_key);
+
+    Ok(())
+}
+
+// ============================================================================
+// Account Validation Contexts
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct SetPoolStatus<'info> {
+    #[account(
+        mut,
+        constraint = pool.authority == authority.key() @ NovaError::Unauthorized
+    )]
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct BeginWindDown<'info> {
+    #[account(
+        mut,
+        constraint = pool.authority == authority.key() @ NovaError::Unauthorized
+    )]
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct FinalizeWindDown<'info> {
+    #[account(
+        mut,
+        constraint = pool.authority == authority.key() @ NovaError::Unauthorized
+    )]
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    #[account(
+        constraint = pool_vault.key() == pool.vault @ NovaError::Unauthorized
+    )]
+    pub pool_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Required when the pool has an underwriter share mint
+    pub share_mint: Option<Box<Account<'info, Mint>>>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimResidual<'info> {
+    #[account(mut)]
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [b"coverage", user.key().as_ref(), pool.key().as_ref()],
+        bump = user_coverage.bump,
+        constraint = user_coverage.pool == pool.key() @ NovaError::InactiveCoverage
+    )]
+    pub user_coverage: Box<Account<'info, UserCoverage>>,
+
+    #[account(
+        mut,
+        constraint = pool_vault.key() == pool.vault @ NovaError::Unauthorized
+    )]
+    pub pool_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = user_token_account.owner == user.key() @ NovaError::Unauthorized,
+        constraint = user_token_account.mint == pool_vault.mint @ NovaError::InvalidPremiumAmount
+    )]
+    pub user_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ClosePoolVault<'info> {
+    #[account(
+        mut,
+        constraint = pool.authority == authority.key() @ NovaError::Unauthorized
+    )]
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    #[account(
+        mut,
+        constraint = pool_vault.key() == pool.vault @ NovaError::Unauthorized
+    )]
+    pub pool_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"pool_directory".as_ref(),
+            &[pool.pool_type as u8],
+            &pool.directory_page.to_le_bytes()
+        ],
+        bump = pool_directory.bump
+    )]
+    pub pool_directory: Box<Account<'info, PoolDirectory>>,
+
+    #[account(
+        mut,
+        constraint = authority_token_account.owner == authority.key() @ NovaError::Unauthorized,
+        constraint = authority_token_account.mint == pool_vault.mint @ NovaError::InvalidPremiumAmount
+    )]
+    pub authority_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+#[event]
+pub struct PoolStatusChangedEvent {
+    pub pool: Pubkey,
+    pub previous_status: PoolStatus,
+    pub status: PoolStatus,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ResidualClaimedEvent {
+    pub user: Pubkey,
+    pub pool: Pubkey,
+    pub premiums_paid: u64,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PoolVaultClosedEvent {
+    pub pool: Pubkey,
+    pub dust_swept: u64,
+    pub timestamp: i64,
+}
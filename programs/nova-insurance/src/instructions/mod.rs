@@ -4,6 +4,11 @@ pub mod validator_management;
 pub mod vrf_integration;
 pub mod distribution_management;
 pub mod yield_integration;
+pub mod capital_management;
+pub mod lifecycle_management;
+pub mod guardian_management;
+pub mod multisig_management;
+pub mod appeal_management;
 
 pub use pool_management::*;
 pub use claims_management::*;
@@ -11,3 +16,8 @@ pub use validator_management::*;
 pub use vrf_integration::*;
 pub use distribution_management::*;
 pub use yield_integration::*;
+pub use capital_management::*;
+pub use lifecycle_management::*;
+pub use guardian_management::*;
+pub use multisig_management::*;
+pub use appeal_management::*;
@@ -0,0 +1,397 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+
+use crate::errors::*;
+use crate::state::*;
+
+/// Create an M-of-N multisig whose signer PDA can be used as a pool authority
+pub fn create_multisig(
+    ctx: Context<CreateMultisig>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+    multisig_index: u8,
+) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    let clock = Clock::get()?;
+
+    Multisig::validate_signers(&signers, threshold)?;
+
+    multisig.creator = ctx.accounts.creator.key();
+    multisig.multisig_index = multisig_index;
+    multisig.signers = signers.clone();
+    multisig.threshold = threshold;
+    multisig.owner_set_seqno = 0;
+    multisig.transaction_count = 0;
+    multisig.signer_bump = *ctx.bumps.get("multisig_signer").unwrap();
+    multisig.bump = *ctx.bumps.get("multisig").unwrap();
+
+    emit!(MultisigCreatedEvent {
+        multisig: multisig.key(),
+        multisig_signer: ctx.accounts.multisig_signer.key(),
+        signers,
+        threshold,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Multisig {} created with threshold {} of {}",
+        multisig.key(),
+        threshold,
+        multisig.signers.len()
+    );
+
+    Ok(())
+}
+
+/// Propose an instruction to be executed by the multisig signer; the proposer
+/// approves it implicitly
+pub fn propose_multisig_transaction(
+    ctx: Context<ProposeMultisigTransaction>,
+    program_id: Pubkey,
+    accounts: Vec<TransactionAccount>,
+    data: Vec<u8>,
+) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    let transaction = &mut ctx.accounts.transaction;
+    let clock = Clock::get()?;
+
+    let proposer_index = multisig.signer_index(&ctx.accounts.proposer.key())?;
+    require!(
+        accounts.len() <= 16 && data.len() <= 512,
+        NovaError::TransactionTooLarge
+    );
+
+    let mut approvals = vec![false; multisig.signers.len()];
+    approvals[proposer_index] = true;
+
+    transaction.multisig = multisig.key();
+    transaction.index = multisig.transaction_count;
+    transaction.proposer = ctx.accounts.proposer.key();
+    transaction.program_id = program_id;
+    transaction.accounts = accounts;
+    transaction.data = data;
+    transaction.approvals = approvals;
+    transaction.owner_set_seqno = multisig.owner_set_seqno;
+    transaction.executed = false;
+    transaction.created_at = clock.unix_timestamp;
+    transaction.bump = *ctx.bumps.get("transaction").unwrap();
+
+    multisig.transaction_count = multisig
+        .transaction_count
+        .checked_add(1)
+        .ok_or(NovaError::MathOverflow)?;
+
+    emit!(MultisigTransactionProposedEvent {
+        multisig: multisig.key(),
+        transaction: transaction.key(),
+        index: transaction.index,
+        proposer: transaction.proposer,
+        program_id,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Multisig transaction {} proposed on {} by {}",
+        transaction.index,
+        multisig.key(),
+        transaction.proposer
+    );
+
+    Ok(())
+}
+
+/// Approve a pending multisig transaction
+pub fn approve_multisig_transaction(ctx: Context<ApproveMultisigTransaction>) -> Result<()> {
+    let multisig = &ctx.accounts.multisig;
+    let transaction = &mut ctx.accounts.transaction;
+    let clock = Clock::get()?;
+
+    let signer_index = multisig.signer_index(&ctx.accounts.signer.key())?;
+    transaction.approvals[signer_index] = true;
+
+    emit!(MultisigTransactionApprovedEvent {
+        multisig: multisig.key(),
+        transaction: transaction.key(),
+        signer: ctx.accounts.signer.key(),
+        approvals: transaction.approval_count() as u8,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Multisig transaction {} approved by {} ({}/{})",
+        transaction.index,
+        ctx.accounts.signer.key(),
+        transaction.approval_count(),
+        multisig.threshold
+    );
+
+    Ok(())
+}
+
+/// Execute a multisig transaction that has reached the approval threshold.
+/// The instruction's accounts must be passed as remaining accounts.
+pub fn execute_multisig_transaction<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteMultisigTransaction<'info>>,
+) -> Result<()> {
+    let multisig = &ctx.accounts.multisig;
+    let clock = Clock::get()?;
+
+    multisig.signer_index(&ctx.accounts.executor.key())?;
+    require!(
+        ctx.accounts.transaction.approval_count() >= multisig.threshold as usize,
+        NovaError::InsufficientApprovals
+    );
+
+    // Mark executed before the CPI so the transaction cannot be replayed
+    ctx.accounts.transaction.executed = true;
+
+    let transaction = &ctx.accounts.transaction;
+    let multisig_signer = ctx.accounts.multisig_signer.key();
+    let instruction = Instruction {
+        program_id: transaction.program_id,
+        accounts: transaction
+            .accounts
+            .iter()
+            .map(|acc| AccountMeta {
+                pubkey: acc.pubkey,
+                is_signer: acc.is_signer || acc.pubkey == multisig_signer,
+                is_writable: acc.is_writable,
+            })
+            .collect(),
+        data: transaction.data.clone(),
+    };
+
+    let mut account_infos = ctx.remaining_accounts.to_vec();
+    account_infos.push(ctx.accounts.multisig_signer.to_account_info());
+
+    let multisig_key = multisig.key();
+    let seeds = &[
+        b"multisig_signer".as_ref(),
+        multisig_key.as_ref(),
+        &[multisig.signer_bump],
+    ];
+    invoke_signed(&instruction, &account_infos, &[&seeds[..]])?;
+
+    emit!(MultisigTransactionExecutedEvent {
+        multisig: multisig_key,
+        transaction: transaction.key(),
+        index: transaction.index,
+        executor: ctx.accounts.executor.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Multisig transaction {} executed on {}",
+        transaction.index,
+        multisig_key
+    );
+
+    Ok(())
+}
+
+/// Replace the signer set and threshold. Must be executed through the multisig
+/// itself; pending transactions proposed under the old signer set are invalidated.
+pub fn set_multisig_config(
+    ctx: Context<SetMultisigConfig>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    let clock = Clock::get()?;
+
+    Multisig::validate_signers(&signers, threshold)?;
+
+    multisig.signers = signers.clone();
+    multisig.threshold = threshold;
+    multisig.owner_set_seqno = multisig
+        .owner_set_seqno
+        .checked_add(1)
+        .ok_or(NovaError::MathOverflow)?;
+
+    emit!(MultisigConfigUpdatedEvent {
+        multisig: multisig.key(),
+        signers,
+        threshold,
+        owner_set_seqno: multisig.owner_set_seqno,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Multisig {} updated to threshold {} of {}",
+        multisig.key(),
+        threshold,
+        multisig.signers.len()
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// Account Validation Contexts
+// ============================================================================
+
+#[derive(Accounts)]
+#[instruction(signers: Vec<Pubkey>, threshold: u8, multisig_index: u8)]
+pub struct CreateMultisig<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = Multisig::LEN,
+        seeds = [b"multisig", creator.key().as_ref(), &[multisig_index]],
+        bump
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+
+    /// CHECK: Data-less PDA that signs on behalf of the multisig
+    #[account(
+        seeds = [b"multisig_signer", multisig.key().as_ref()],
+        bump
+    )]
+    pub multisig_signer: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeMultisigTransaction<'info> {
+    #[account(
+        mut,
+        seeds = [b"multisig", multisig.creator.as_ref(), &[multisig.multisig_index]],
+        bump = multisig.bump
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = MultisigTransaction::LEN,
+        seeds = [
+            b"multisig_tx",
+            multisig.key().as_ref(),
+            &multisig.transaction_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub transaction: Box<Account<'info, MultisigTransaction>>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveMultisigTransaction<'info> {
+    #[account(
+        seeds = [b"multisig", multisig.creator.as_ref(), &[multisig.multisig_index]],
+        bump = multisig.bump
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+
+    #[account(
+        mut,
+        constraint = transaction.multisig == multisig.key() @ NovaError::Unauthorized,
+        constraint = !transaction.executed @ NovaError::TransactionAlreadyExecuted,
+        constraint = transaction.owner_set_seqno == multisig.owner_set_seqno @ NovaError::StaleMultisigTransaction
+    )]
+    pub transaction: Box<Account<'info, MultisigTransaction>>,
+
+    pub signer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteMultisigTransaction<'info> {
+    #[account(
+        seeds = [b"multisig", multisig.creator.as_ref(), &[multisig.multisig_index]],
+        bump = multisig.bump
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+
+    /// CHECK: Data-less PDA that signs on behalf of the multisig
+    #[account(
+        mut,
+        seeds = [b"multisig_signer", multisig.key().as_ref()],
+        bump = multisig.signer_bump
+    )]
+    pub multisig_signer: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = transaction.multisig == multisig.key() @ NovaError::Unauthorized,
+        constraint = !transaction.executed @ NovaError::TransactionAlreadyExecuted,
+        constraint = transaction.owner_set_seqno == multisig.owner_set_seqno @ NovaError::StaleMultisigTransaction
+    )]
+    pub transaction: Box<Account<'info, MultisigTransaction>>,
+
+    pub executor: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetMultisigConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"multisig", multisig.creator.as_ref(), &[multisig.multisig_index]],
+        bump = multisig.bump
+    )]
+    pub multisig: Box<Account<'info, Multisig>>,
+
+    #[account(
+        seeds = [b"multisig_signer", multisig.key().as_ref()],
+        bump = multisig.signer_bump
+    )]
+    pub multisig_signer: Signer<'info>,
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+#[event]
+pub struct MultisigCreatedEvent {
+    pub multisig: Pubkey,
+    pub multisig_signer: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigTransactionProposedEvent {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub index: u64,
+    pub proposer: Pubkey,
+    pub program_id: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigTransactionApprovedEvent {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub signer: Pubkey,
+    pub approvals: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigTransactionExecutedEvent {
+    pub multisig: Pubkey,
+    pub transaction: Pubkey,
+    pub index: u64,
+    pub executor: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultisigConfigUpdatedEvent {
+    pub multisig: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub owner_set_seqno: u32,
+    pub timestamp: i64,
+}
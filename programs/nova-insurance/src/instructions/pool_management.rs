@@ -4,6 +4,40 @@ use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 use crate::errors::*;
 use crate::state::*;
 
+/// Initialize a page of the directory listing all pools of one type
+///
+/// Page 0 can be opened at any time; later pages only once the previous
+/// page is full, so pages can be enumerated from 0 without gaps.
+pub fn initialize_pool_directory(
+    ctx: Context<InitializePoolDirectory>,
+    pool_type: PoolType,
+    page: u32,
+) -> Result<()> {
+    if page > 0 {
+        let previous_page = ctx
+            .accounts
+            .previous_page
+            .as_ref()
+            .ok_or(NovaError::PreviousDirectoryPageNotFull)?;
+        require!(
+            previous_page.is_full(),
+            NovaError::PreviousDirectoryPageNotFull
+        );
+    }
+
+    let directory = &mut ctx.accounts.pool_directory;
+
+    directory.pool_type = pool_type;
+    directory.page = page;
+    directory.pools = Vec::new();
+    directory.total_pools = 0;
+    directory.bump = *ctx.bumps.get("pool_directory").unwrap();
+
+    msg!("Pool directory page {} initialized for {:?} pools", page, pool_type);
+
+    Ok(())
+}
+
 /// Initialize a new insurance pool
 pub fn initialize_pool(
     ctx: Context<InitializePool>,
@@ -12,6 +46,7 @@ pub fn initialize_pool(
     coverage_amount: u64,
     min_validators: u8,
     claim_period: i64,
+    pool_index: u8,
 ) -> Result<()> {
     let pool = &mut ctx.accounts.pool;
     let clock = Clock::get()?;
@@ -23,6 +58,12 @@ pub fn initialize_pool(
         NovaError::InvalidCoverageAmount
     );
     require!(min_validators >= 3, NovaError::InsufficientValidators);
+    // Leave room for an appeal panel larger than the first-round panel, as
+    // PoolConfigParams::validate requires of every later config change
+    require!(
+        (min_validators as usize) < ClaimRequest::MAX_VALIDATORS,
+        NovaError::InvalidAppealTerms
+    );
     require!(claim_period > 0, NovaError::ClaimPeriodExpired);
 
     // Get pool key before mutating
@@ -34,6 +75,8 @@ pub fn initialize_pool(
     pool.pool_id = pool_key;
     pool.pool_type = pool_type;
     pool.authority = authority_key;
+    pool.creator = authority_key;
+    pool.pool_index = pool_index;
     pool.vault = vault_key;
     pool.yield_vault = None; // Initialize as None, can be set later
     pool.premium_amount = premium_amount;
@@ -46,13 +89,72 @@ pub fn initialize_pool(
     pool.yield_deposited = 0;
     pool.yield_earned = 0;
     pool.last_yield_update = clock.unix_timestamp;
+    pool.share_mint = None; // Set by initialize_share_mint
+    pool.withdrawal_cooldown = 0;
+    pool.reserved_shares = 0;
+    pool.claims_under_validation = 0;
+    pool.approved_unpaid_claims = 0;
+    pool.config_update_delay = InsurancePool::DEFAULT_CONFIG_UPDATE_DELAY;
+    pool.billing_period = InsurancePool::DEFAULT_BILLING_PERIOD;
+    pool.grace_period = InsurancePool::DEFAULT_GRACE_PERIOD;
+    pool.prepay_discount_bps = 0;
+    pool.max_prepay_periods = InsurancePool::DEFAULT_MAX_PREPAY_PERIODS;
+    pool.premium_rate_bps = 0; // Flat premium until a rate is configured
+    pool.scale_by_pool_type = false;
+    pool.risk_tier_multipliers_bps = InsurancePool::DEFAULT_RISK_TIER_MULTIPLIERS_BPS;
+    pool.waiting_period = InsurancePool::DEFAULT_WAITING_PERIOD;
+    pool.incident_waiting_periods = [0; 6];
+    pool.deductible = 0;
+    pool.coinsurance_bps = 0;
+    pool.max_payout_per_claim = 0;
+    pool.annual_aggregate_limit = 0;
+    pool.max_claims_per_year = 0;
+    pool.appeal_window = InsurancePool::DEFAULT_APPEAL_WINDOW;
+    pool.appeal_bond = 0;
+    pool.appeal_panel_size = min_validators
+        .saturating_mul(2)
+        .saturating_add(1)
+        .min(ClaimRequest::MAX_VALIDATORS as u8);
+    pool.voting_period = InsurancePool::DEFAULT_VOTING_PERIOD;
+    pool.slash_redistribution_bps = InsurancePool::DEFAULT_SLASH_REDISTRIBUTION_BPS;
+    pool.unstake_cooldown = InsurancePool::DEFAULT_UNSTAKE_COOLDOWN;
+    pool.min_validator_stake = InsurancePool::DEFAULT_MIN_VALIDATOR_STAKE;
+    pool.validator_reward_bps = 0; // No validator rewards until configured
+    pool.validation_reward = 0;
+    pool.min_validator_reputation = InsurancePool::DEFAULT_MIN_VALIDATOR_REPUTATION;
+    pool.bar_member_validators = false;
+    pool.status = PoolStatus::Active;
+    pool.member_accounts = 0;
+    pool.total_member_premiums = 0;
+    pool.residual_balance = 0;
+    pool.residual_premiums = 0;
+    pool.residual_claim_deadline = 0;
+    pool.appeal_bonds_held = 0;
+    pool.last_rejection_at = 0;
+    pool.guardian = None; // Set by set_guardian
+    pool.paused_categories = 0;
+    pool.pending_authority = None;
+    pool.claim_sequence = 0;
+    pool.vrf_initialized = false;
     pool.created_at = clock.unix_timestamp;
     pool.bump = *ctx.bumps.get("pool").unwrap();
 
+    // List the pool in the directory for its type; a full page means the
+    // creator lists it on the next page instead
+    let directory = &mut ctx.accounts.pool_directory;
+    require!(!directory.is_full(), NovaError::PoolDirectoryFull);
+    directory.pools.push(pool_key);
+    pool.directory_page = directory.page;
+    directory.total_pools = directory
+        .total_pools
+        .checked_add(1)
+        .ok_or(NovaError::MathOverflow)?;
+
     emit!(PoolCreatedEvent {
         pool_id: pool_key,
         authority: authority_key,
         pool_type,
+        pool_index,
         premium_amount,
         coverage_amount,
         min_validators,
@@ -76,6 +178,9 @@ pub fn join_pool(ctx: Context<JoinPool>, coverage_amount: u64) -> Result<()> {
     let user_coverage = &mut ctx.accounts.user_coverage;
     let clock = Clock::get()?;
 
+    pool.require_not_paused(PauseCategory::MEMBERSHIP)?;
+    pool.require_active()?;
+
     // Validate coverage amount
     require!(
         coverage_amount <= pool.coverage_amount,
@@ -83,43 +188,69 @@ pub fn join_pool(ctx: Context<JoinPool>, coverage_amount: u64) -> Result<()> {
     );
     require!(coverage_amount > 0, NovaError::InvalidCoverageAmount);
 
-    // Transfer premium from user to pool vault
-    let transfer_ctx = CpiContext::new(
-        ctx.accounts.token_program.to_account_info(),
-        Transfer {
-            from: ctx.accounts.user_token_account.to_account_info(),
-            to: ctx.accounts.pool_vault.to_account_info(),
-            authority: ctx.accounts.user.to_account_info(),
-        },
-    );
-    token::transfer(transfer_ctx, pool.premium_amount)?;
+    // New members are priced at the standard risk tier
+    let premium = pool.quote_premium(coverage_amount, RiskTier::Standard)?;
+
+    // Transfer premium from user to pool vault, less the validator reward share
+    let pooled = collect_premium(
+        pool,
+        &ctx.accounts.token_program,
+        &ctx.accounts.user_token_account,
+        &ctx.accounts.pool_vault,
+        &mut ctx.accounts.validator_rewards,
+        &ctx.accounts.validator_rewards_vault,
+        &ctx.accounts.user,
+        premium,
+    )?;
 
     // Initialize user coverage
     user_coverage.user = ctx.accounts.user.key();
     user_coverage.pool = pool.key();
-    user_coverage.premiums_paid = pool.premium_amount;
+    user_coverage.premiums_paid = premium;
     user_coverage.last_payment = clock.unix_timestamp;
     user_coverage.coverage_active = true;
     user_coverage.coverage_amount = coverage_amount;
+    user_coverage.risk_tier = RiskTier::Standard;
     user_coverage.claims_made = 0;
     user_coverage.joined_at = clock.unix_timestamp;
+    user_coverage.covered_from = clock.unix_timestamp;
+    user_coverage.prior_coverage_amount = coverage_amount;
+    user_coverage.coverage_increased_at = 0;
+    user_coverage.paid_through = clock
+        .unix_timestamp
+        .checked_add(pool.billing_period)
+        .ok_or(NovaError::MathOverflow)?;
+    user_coverage.period_premium = pooled;
+    user_coverage.open_claims = 0;
+    user_coverage.policy_year_start = clock.unix_timestamp;
+    user_coverage.claimed_this_year = 0;
+    user_coverage.paid_this_year = 0;
     user_coverage.bump = *ctx.bumps.get("user_coverage").unwrap();
 
     // Update pool stats
     pool.total_pooled = pool
         .total_pooled
-        .checked_add(pool.premium_amount)
+        .checked_add(pooled)
         .ok_or(NovaError::InvalidCoverageAmount)?;
     pool.total_members = pool
         .total_members
         .checked_add(1)
         .ok_or(NovaError::InvalidCoverageAmount)?;
+    pool.member_accounts = pool
+        .member_accounts
+        .checked_add(1)
+        .ok_or(NovaError::MathOverflow)?;
+    pool.total_member_premiums = pool
+        .total_member_premiums
+        .checked_add(premium)
+        .ok_or(NovaError::MathOverflow)?;
 
     emit!(UserJoinedEvent {
         user: ctx.accounts.user.key(),
         pool: pool.key(),
         coverage_amount,
-        premium_paid: pool.premium_amount,
+        premium_paid: premium,
+        paid_through: user_coverage.paid_through,
         timestamp: clock.unix_timestamp,
     });
 
@@ -133,70 +264,663 @@ pub fn join_pool(ctx: Context<JoinPool>, coverage_amount: u64) -> Result<()> {
     Ok(())
 }
 
-/// Pay monthly premium to maintain coverage
-pub fn pay_premium(ctx: Context<PayPremium>) -> Result<()> {
-    let pool = &ctx.accounts.pool;
+/// Pay premium for one or more billing periods to maintain coverage
+///
+/// Coverage that is still within its grace period is extended from its
+/// paid-through date. Lapsed coverage is reinstated from now, so incidents
+/// during the gap are never covered. Paying several periods up front earns
+/// the pool's prepay discount, but coverage is never paid more than
+/// `max_prepay_periods` billing periods ahead of now.
+pub fn pay_premium(ctx: Context<PayPremium>, periods: u8) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
     let user_coverage = &mut ctx.accounts.user_coverage;
     let clock = Clock::get()?;
 
+    pool.require_not_paused(PauseCategory::MEMBERSHIP)?;
+
     // Verify coverage exists
     require!(
         user_coverage.user == ctx.accounts.user.key(),
         NovaError::UnauthorizedValidator
     );
 
-    // Transfer premium from user to pool vault
-    let transfer_ctx = CpiContext::new(
-        ctx.accounts.token_program.to_account_info(),
-        Transfer {
-            from: ctx.accounts.user_token_account.to_account_info(),
-            to: ctx.accounts.pool_vault.to_account_info(),
-            authority: ctx.accounts.user.to_account_info(),
-        },
+    // Existing members keep paying while the pool is paused, but no new
+    // coverage is sold once it is winding down
+    require!(
+        pool.status == PoolStatus::Active || pool.status == PoolStatus::Paused,
+        NovaError::PoolNotActive
+    );
+    require!(
+        periods >= 1 && periods <= pool.max_prepay_periods,
+        NovaError::InvalidPremiumPeriods
     );
-    token::transfer(transfer_ctx, pool.premium_amount)?;
+
+    // Extend or reinstate the paid period
+    let lapsed = !user_coverage.coverage_active
+        || user_coverage.is_overdue(clock.unix_timestamp, pool.grace_period);
+    if lapsed {
+        user_coverage.covered_from = clock.unix_timestamp;
+        user_coverage.paid_through = clock.unix_timestamp;
+    }
+    let extension = pool
+        .billing_period
+        .checked_mul(periods as i64)
+        .ok_or(NovaError::MathOverflow)?;
+    user_coverage.paid_through = user_coverage
+        .paid_through
+        .checked_add(extension)
+        .ok_or(NovaError::MathOverflow)?;
+
+    // Repeated prepayments cannot push coverage past the prepay horizon
+    let max_horizon = pool
+        .billing_period
+        .checked_mul(pool.max_prepay_periods as i64)
+        .and_then(|horizon| clock.unix_timestamp.checked_add(horizon))
+        .ok_or(NovaError::MathOverflow)?;
+    require!(
+        user_coverage.paid_through <= max_horizon,
+        NovaError::PrepayHorizonExceeded
+    );
+
+    // Re-quote at the pool's current pricing for the member's coverage and tier
+    let premium = pool.quote_premium(user_coverage.coverage_amount, user_coverage.risk_tier)?;
+    let (amount, discount) = pool.premium_for_periods(premium, periods)?;
+
+    // Transfer premium from user to pool vault, less the validator reward share
+    let pooled = collect_premium(
+        pool,
+        &ctx.accounts.token_program,
+        &ctx.accounts.user_token_account,
+        &ctx.accounts.pool_vault,
+        &mut ctx.accounts.validator_rewards,
+        &ctx.accounts.validator_rewards_vault,
+        &ctx.accounts.user,
+        amount,
+    )?;
+
+    if !user_coverage.coverage_active {
+        pool.total_members = pool
+            .total_members
+            .checked_add(1)
+            .ok_or(NovaError::MathOverflow)?;
+    }
 
     // Update user coverage
     user_coverage.premiums_paid = user_coverage
         .premiums_paid
-        .checked_add(pool.premium_amount)
+        .checked_add(amount)
         .ok_or(NovaError::InvalidPremiumAmount)?;
     user_coverage.last_payment = clock.unix_timestamp;
     user_coverage.coverage_active = true;
+    user_coverage.period_premium = pooled / periods as u64;
+
+    pool.total_pooled = pool
+        .total_pooled
+        .checked_add(pooled)
+        .ok_or(NovaError::MathOverflow)?;
+    pool.total_member_premiums = pool
+        .total_member_premiums
+        .checked_add(amount)
+        .ok_or(NovaError::MathOverflow)?;
 
     emit!(PremiumPaidEvent {
         user: ctx.accounts.user.key(),
         pool: pool.key(),
-        amount: pool.premium_amount,
+        amount,
+        periods,
+        discount,
         total_paid: user_coverage.premiums_paid,
+        paid_through: user_coverage.paid_through,
         timestamp: clock.unix_timestamp,
     });
 
     msg!(
-        "Premium paid: {} USDC by {} for pool {}",
-        pool.premium_amount,
+        "Premium paid: {} USDC for {} period(s) by {} for pool {}, covered through {}",
+        amount,
+        periods,
         ctx.accounts.user.key(),
-        pool.key()
+        pool.key(),
+        user_coverage.paid_through
     );
 
     Ok(())
 }
 
+/// Change the caller's own coverage level, re-quoting their premium
+///
+/// Both take effect immediately. Increases are charged pro rata for the
+/// remainder of the already-paid period; decreases refund, pro rata for that
+/// remainder, the pooled premium for the coverage given up.
+pub fn update_coverage(ctx: Context<UpdateCoverage>, new_coverage_amount: u64) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let user_coverage = &mut ctx.accounts.user_coverage;
+    let clock = Clock::get()?;
+
+    pool.require_not_paused(PauseCategory::MEMBERSHIP)?;
+    pool.require_active()?;
+    require!(
+        user_coverage.coverage_active,
+        NovaError::InactiveCoverage
+    );
+    require!(
+        !user_coverage.is_overdue(clock.unix_timestamp, pool.grace_period),
+        NovaError::PremiumOverdue
+    );
+    require!(new_coverage_amount > 0, NovaError::InvalidCoverageAmount);
+    require!(
+        new_coverage_amount <= pool.coverage_amount,
+        NovaError::ExcessiveCoverageAmount
+    );
+
+    let old_coverage_amount = user_coverage.coverage_amount;
+    let old_premium = pool.quote_premium(old_coverage_amount, user_coverage.risk_tier)?;
+    let new_premium = pool.quote_premium(new_coverage_amount, user_coverage.risk_tier)?;
+
+    // Charge the premium difference for the time left in the paid period
+    let remaining = user_coverage
+        .paid_through
+        .saturating_sub(clock.unix_timestamp)
+        .max(0);
+    let amount_charged = if new_premium > old_premium {
+        ((new_premium - old_premium) as u128)
+            .checked_mul(remaining as u128)
+            .ok_or(NovaError::MathOverflow)?
+            .checked_div(pool.billing_period as u128)
+            .ok_or(NovaError::MathOverflow)? as u64
+    } else {
+        0
+    };
+
+    if amount_charged > 0 {
+        let pooled = collect_premium(
+            pool,
+            &ctx.accounts.token_program,
+            &ctx.accounts.user_token_account,
+            &ctx.accounts.pool_vault,
+            &mut ctx.accounts.validator_rewards,
+            &ctx.accounts.validator_rewards_vault,
+            &ctx.accounts.user,
+            amount_charged,
+        )?;
+
+        user_coverage.premiums_paid = user_coverage
+            .premiums_paid
+            .checked_add(amount_charged)
+            .ok_or(NovaError::MathOverflow)?;
+        pool.total_pooled = pool
+            .total_pooled
+            .checked_add(pooled)
+            .ok_or(NovaError::MathOverflow)?;
+        pool.total_member_premiums = pool
+            .total_member_premiums
+            .checked_add(amount_charged)
+            .ok_or(NovaError::MathOverflow)?;
+        user_coverage.period_premium = new_premium
+            .checked_sub(pool.validator_reward_share(new_premium)?)
+            .ok_or(NovaError::MathOverflow)?;
+    }
+
+    // Scale the pooled period premium down to the new coverage, keeping any
+    // prepay discount, and refund the difference for the time left
+    let amount_refunded = if new_premium < old_premium {
+        let new_period_premium = (user_coverage.period_premium as u128)
+            .checked_mul(new_premium as u128)
+            .ok_or(NovaError::MathOverflow)?
+            .checked_div(old_premium as u128)
+            .ok_or(NovaError::MathOverflow)? as u64;
+        let refund = ((user_coverage.period_premium - new_period_premium) as u128)
+            .checked_mul(remaining as u128)
+            .ok_or(NovaError::MathOverflow)?
+            .checked_div(pool.billing_period as u128)
+            .ok_or(NovaError::MathOverflow)? as u64;
+        user_coverage.period_premium = new_period_premium;
+        refund.min(user_coverage.premiums_paid)
+    } else {
+        0
+    };
+
+    if amount_refunded > 0 {
+        require!(
+            ctx.accounts.pool_vault.amount >= amount_refunded,
+            NovaError::InsufficientPoolFunds
+        );
+
+        let seeds = pool.signer_seeds();
+        let signer = &[&seeds[..]];
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.pool_vault.to_account_info(),
+                to: ctx.accounts.user_token_account.to_account_info(),
+                authority: pool.to_account_info(),
+            },
+            signer,
+        );
+        token::transfer(transfer_ctx, amount_refunded)?;
+
+        user_coverage.premiums_paid = user_coverage.premiums_paid.saturating_sub(amount_refunded);
+        pool.total_pooled = pool.total_pooled.saturating_sub(amount_refunded);
+        pool.total_member_premiums = pool
+            .total_member_premiums
+            .saturating_sub(amount_refunded);
+    }
+
+    // The increased portion only covers incidents after a fresh waiting period
+    if new_coverage_amount > old_coverage_amount {
+        user_coverage.prior_coverage_amount = user_coverage
+            .coverage_for_incident(clock.unix_timestamp, pool.max_waiting_period());
+        user_coverage.coverage_increased_at = clock.unix_timestamp;
+    }
+    user_coverage.coverage_amount = new_coverage_amount;
+
+    emit!(CoverageUpdatedEvent {
+        user: user_coverage.user,
+        pool: pool.key(),
+        old_coverage_amount,
+        new_coverage_amount,
+        premium_per_period: new_premium,
+        amount_charged,
+        amount_refunded,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Coverage for {} in pool {} changed from {} to {} USDC, premium now {} USDC",
+        user_coverage.user,
+        pool.key(),
+        old_coverage_amount,
+        new_coverage_amount,
+        new_premium
+    );
+
+    Ok(())
+}
+
+/// Assign a member's risk tier, applied from their next premium payment
+pub fn set_member_risk_tier(ctx: Context<SetMemberRiskTier>, risk_tier: RiskTier) -> Result<()> {
+    let pool = &ctx.accounts.pool;
+    let user_coverage = &mut ctx.accounts.user_coverage;
+    let clock = Clock::get()?;
+
+    user_coverage.risk_tier = risk_tier;
+
+    emit!(MemberRiskTierUpdatedEvent {
+        user: user_coverage.user,
+        pool: pool.key(),
+        risk_tier,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Risk tier for {} in pool {} set to {:?}",
+        user_coverage.user,
+        pool.key(),
+        risk_tier
+    );
+
+    Ok(())
+}
+
+/// Leave a pool, refunding the unearned premium and closing the coverage account
+pub fn leave_pool(ctx: Context<LeavePool>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    ctx.accounts.pool.require_not_paused(PauseCategory::MEMBERSHIP)?;
+
+    let user_coverage = &ctx.accounts.user_coverage;
+
+    // Once closed, members receive their share through claim_residual instead
+    require!(
+        ctx.accounts.pool.status != PoolStatus::Closed,
+        NovaError::PoolNotActive
+    );
+    require!(user_coverage.open_claims == 0, NovaError::OpenClaimsExist);
+
+    let refund = user_coverage
+        .unearned_premium(clock.unix_timestamp, ctx.accounts.pool.billing_period)?;
+    let was_active = user_coverage.coverage_active;
+    let premiums_paid = user_coverage.premiums_paid;
+
+    if refund > 0 {
+        require!(
+            ctx.accounts.pool_vault.amount >= refund,
+            NovaError::InsufficientPoolFunds
+        );
+
+        let seeds = ctx.accounts.pool.signer_seeds();
+        let signer = &[&seeds[..]];
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.pool_vault.to_account_info(),
+                to: ctx.accounts.user_token_account.to_account_info(),
+                authority: ctx.accounts.pool.to_account_info(),
+            },
+            signer,
+        );
+        token::transfer(transfer_ctx, refund)?;
+    }
+
+    let pool = &mut ctx.accounts.pool;
+    pool.total_pooled = pool.total_pooled.saturating_sub(refund);
+    if was_active {
+        pool.total_members = pool.total_members.saturating_sub(1);
+    }
+    pool.member_accounts = pool.member_accounts.saturating_sub(1);
+    pool.total_member_premiums = pool.total_member_premiums.saturating_sub(premiums_paid);
+
+    emit!(UserLeftEvent {
+        user: ctx.accounts.user.key(),
+        pool: pool.key(),
+        refund,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "User {} left pool {} with {} USDC refunded",
+        ctx.accounts.user.key(),
+        pool.key(),
+        refund
+    );
+
+    Ok(())
+}
+
+/// Lapse coverage whose premiums are overdue beyond the pool's grace period
+///
+/// Permissionless crank: anyone may call it once a member is overdue.
+pub fn lapse_coverage(ctx: Context<LapseCoverage>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let user_coverage = &mut ctx.accounts.user_coverage;
+    let clock = Clock::get()?;
+
+    require!(
+        user_coverage.coverage_active,
+        NovaError::InactiveCoverage
+    );
+    require!(
+        user_coverage.is_overdue(clock.unix_timestamp, pool.grace_period),
+        NovaError::CoverageNotOverdue
+    );
+
+    user_coverage.coverage_active = false;
+    pool.total_members = pool.total_members.saturating_sub(1);
+
+    emit!(CoverageLapsedEvent {
+        user: user_coverage.user,
+        pool: pool.key(),
+        paid_through: user_coverage.paid_through,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Coverage lapsed for {} in pool {} (paid through {})",
+        user_coverage.user,
+        pool.key(),
+        user_coverage.paid_through
+    );
+
+    Ok(())
+}
+
+/// Queue a change to the pool's terms, executable after the pool's timelock
+///
+/// Pools without members or funds have no one to protect, so changes there
+/// are executable immediately. The timelock is checked again on execution in
+/// case the pool has gained members or funds since.
+pub fn propose_pool_config(
+    ctx: Context<ProposePoolConfig>,
+    params: PoolConfigParams,
+) -> Result<()> {
+    let pool = &ctx.accounts.pool;
+    let pending = &mut ctx.accounts.pending_config;
+    let clock = Clock::get()?;
+
+    params.validate()?;
+
+    let executable_at = clock
+        .unix_timestamp
+        .checked_add(pool.config_delay())
+        .ok_or(NovaError::MathOverflow)?;
+
+    pending.pool = pool.key();
+    pending.params = params.clone();
+    pending.proposed_at = clock.unix_timestamp;
+    pending.executable_at = executable_at;
+    pending.bump = *ctx.bumps.get("pending_config").unwrap();
+
+    emit!(PoolConfigProposedEvent {
+        pool: pool.key(),
+        current: pool.config(),
+        proposed: params,
+        executable_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Config change proposed for pool {}, executable at {}",
+        pool.key(),
+        executable_at
+    );
+
+    Ok(())
+}
+
+/// Apply a queued config change once its timelock has elapsed, measured
+/// against the pool's current state
+pub fn execute_pool_config(ctx: Context<ExecutePoolConfig>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let pending = &ctx.accounts.pending_config;
+    let clock = Clock::get()?;
+
+    // A proposal made while the pool was empty still waits out the full
+    // timelock if members or funds have arrived since
+    let required_at = pending
+        .proposed_at
+        .checked_add(pool.config_delay())
+        .ok_or(NovaError::MathOverflow)?;
+    require!(
+        clock.unix_timestamp >= pending.executable_at.max(required_at),
+        NovaError::ConfigTimelockActive
+    );
+
+    pool.apply_config(&pending.params);
+
+    emit!(PoolConfigUpdatedEvent {
+        pool: pool.key(),
+        params: pool.config(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Config change applied to pool {}", pool.key());
+
+    Ok(())
+}
+
+/// Discard a queued config change
+pub fn cancel_pool_config(ctx: Context<CancelPoolConfig>) -> Result<()> {
+    let pool = &ctx.accounts.pool;
+    let clock = Clock::get()?;
+
+    emit!(PoolConfigCancelledEvent {
+        pool: pool.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Config change cancelled for pool {}", pool.key());
+
+    Ok(())
+}
+
+/// Propose a new pool authority; takes effect once the new authority accepts
+pub fn propose_authority_transfer(
+    ctx: Context<ProposeAuthorityTransfer>,
+    new_authority: Pubkey,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let clock = Clock::get()?;
+
+    pool.pending_authority = Some(new_authority);
+
+    emit!(AuthorityTransferProposedEvent {
+        pool: pool.key(),
+        current_authority: pool.authority,
+        pending_authority: new_authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Authority transfer proposed for pool {}: {} -> {}",
+        pool.key(),
+        pool.authority,
+        new_authority
+    );
+
+    Ok(())
+}
+
+/// Accept a pending authority transfer, completing the handover
+pub fn accept_authority_transfer(ctx: Context<AcceptAuthorityTransfer>) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let clock = Clock::get()?;
+
+    let previous_authority = pool.authority;
+    pool.authority = ctx.accounts.new_authority.key();
+    pool.pending_authority = None;
+
+    emit!(AuthorityTransferredEvent {
+        pool: pool.key(),
+        previous_authority,
+        new_authority: pool.authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Authority for pool {} transferred from {} to {}",
+        pool.key(),
+        previous_authority,
+        pool.authority
+    );
+
+    Ok(())
+}
+
+/// Transfer a premium payment from the member, routing the pool's validator reward
+/// share into the validator rewards vault and the rest into the pool vault.
+/// Returns the amount added to the pool vault.
+#[allow(clippy::too_many_arguments)]
+fn collect_premium<'info>(
+    pool: &InsurancePool,
+    token_program: &Program<'info, Token>,
+    user_token_account: &Account<'info, TokenAccount>,
+    pool_vault: &Account<'info, TokenAccount>,
+    validator_rewards: &mut Option<Box<Account<'info, ValidatorRewards>>>,
+    validator_rewards_vault: &Option<Box<Account<'info, TokenAccount>>>,
+    user: &Signer<'info>,
+    amount: u64,
+) -> Result<u64> {
+    let reward_share = pool.validator_reward_share(amount)?;
+    let pooled = amount
+        .checked_sub(reward_share)
+        .ok_or(NovaError::MathOverflow)?;
+
+    if reward_share > 0 {
+        let (rewards, rewards_vault) = match (validator_rewards.as_mut(), validator_rewards_vault) {
+            (Some(rewards), Some(vault)) if vault.key() == rewards.vault => (rewards, vault),
+            _ => return Err(NovaError::ValidatorRewardsVaultRequired.into()),
+        };
+        let transfer_ctx = CpiContext::new(
+            token_program.to_account_info(),
+            Transfer {
+                from: user_token_account.to_account_info(),
+                to: rewards_vault.to_account_info(),
+                authority: user.to_account_info(),
+            },
+        );
+        token::transfer(transfer_ctx, reward_share)?;
+        rewards.total_funded = rewards
+            .total_funded
+            .checked_add(reward_share)
+            .ok_or(NovaError::MathOverflow)?;
+    }
+
+    let transfer_ctx = CpiContext::new(
+        token_program.to_account_info(),
+        Transfer {
+            from: user_token_account.to_account_info(),
+            to: pool_vault.to_account_info(),
+            authority: user.to_account_info(),
+        },
+    );
+    token::transfer(transfer_ctx, pooled)?;
+
+    Ok(pooled)
+}
+
 // ============================================================================
 // Account Validation Contexts
 // ============================================================================
 
 #[derive(Accounts)]
+#[instruction(pool_type: PoolType, page: u32)]
+pub struct InitializePoolDirectory<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + PoolDirectory::INIT_SPACE,
+        seeds = [b"pool_directory".as_ref(), &[pool_type as u8], &page.to_le_bytes()],
+        bump
+    )]
+    pub pool_directory: Box<Account<'info, PoolDirectory>>,
+
+    /// Preceding directory page; required for every page after the first
+    #[account(
+        seeds = [
+            b"pool_directory".as_ref(),
+            &[pool_type as u8],
+            &page.saturating_sub(1).to_le_bytes()
+        ],
+        bump = previous_page.bump
+    )]
+    pub previous_page: Option<Box<Account<'info, PoolDirectory>>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(
+    pool_type: PoolType,
+    premium_amount: u64,
+    coverage_amount: u64,
+    min_validators: u8,
+    claim_period: i64,
+    pool_index: u8
+)]
 pub struct InitializePool<'info> {
     #[account(
         init,
         payer = authority,
         space = 8 + InsurancePool::INIT_SPACE,
-        seeds = [b"pool", authority.key().as_ref()],
+        seeds = [b"pool", authority.key().as_ref(), &[pool_index]],
         bump
     )]
     pub pool: Box<Account<'info, InsurancePool>>,
 
+    /// Any directory page for the pool type with room left
+    #[account(
+        mut,
+        seeds = [
+            b"pool_directory".as_ref(),
+            &[pool_type as u8],
+            &pool_directory.page.to_le_bytes()
+        ],
+        bump = pool_directory.bump
+    )]
+    pub pool_directory: Box<Account<'info, PoolDirectory>>,
+
     #[account(
         init,
         payer = authority,
@@ -244,6 +968,17 @@ pub struct JoinPool<'info> {
     )]
     pub user_token_account: Box<Account<'info, TokenAccount>>,
 
+    /// Required when the pool sets aside a validator reward share of premiums
+    #[account(
+        mut,
+        seeds = [b"validator_rewards", pool.key().as_ref()],
+        bump = validator_rewards.bump
+    )]
+    pub validator_rewards: Option<Box<Account<'info, ValidatorRewards>>>,
+
+    #[account(mut)]
+    pub validator_rewards_vault: Option<Box<Account<'info, TokenAccount>>>,
+
     #[account(mut)]
     pub user: Signer<'info>,
 
@@ -277,12 +1012,217 @@ pub struct PayPremium<'info> {
     )]
     pub user_token_account: Box<Account<'info, TokenAccount>>,
 
+    /// Required when the pool sets aside a validator reward share of premiums
+    #[account(
+        mut,
+        seeds = [b"validator_rewards", pool.key().as_ref()],
+        bump = validator_rewards.bump
+    )]
+    pub validator_rewards: Option<Box<Account<'info, ValidatorRewards>>>,
+
+    #[account(mut)]
+    pub validator_rewards_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateCoverage<'info> {
+    #[account(mut)]
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    #[account(
+        mut,
+        seeds = [b"coverage", user.key().as_ref(), pool.key().as_ref()],
+        bump = user_coverage.bump,
+        constraint = user_coverage.pool == pool.key() @ NovaError::InactiveCoverage
+    )]
+    pub user_coverage: Box<Account<'info, UserCoverage>>,
+
+    #[account(
+        mut,
+        constraint = pool_vault.key() == pool.vault @ NovaError::UnauthorizedValidator
+    )]
+    pub pool_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = user_token_account.owner == user.key() @ NovaError::UnauthorizedValidator,
+        constraint = user_token_account.mint == pool_vault.mint @ NovaError::InvalidPremiumAmount
+    )]
+    pub user_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Required when the pool sets aside a validator reward share of premiums
+    #[account(
+        mut,
+        seeds = [b"validator_rewards", pool.key().as_ref()],
+        bump = validator_rewards.bump
+    )]
+    pub validator_rewards: Option<Box<Account<'info, ValidatorRewards>>>,
+
+    #[account(mut)]
+    pub validator_rewards_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct SetMemberRiskTier<'info> {
+    #[account(
+        constraint = pool.authority == authority.key() @ NovaError::Unauthorized
+    )]
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    #[account(
+        mut,
+        seeds = [b"coverage", user_coverage.user.as_ref(), pool.key().as_ref()],
+        bump = user_coverage.bump,
+        constraint = user_coverage.pool == pool.key() @ NovaError::InactiveCoverage
+    )]
+    pub user_coverage: Box<Account<'info, UserCoverage>>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct LeavePool<'info> {
+    #[account(mut)]
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    #[account(
+        mut,
+        close = user,
+        seeds = [b"coverage", user.key().as_ref(), pool.key().as_ref()],
+        bump = user_coverage.bump,
+        constraint = user_coverage.pool == pool.key() @ NovaError::InactiveCoverage
+    )]
+    pub user_coverage: Box<Account<'info, UserCoverage>>,
+
+    #[account(
+        mut,
+        constraint = pool_vault.key() == pool.vault @ NovaError::UnauthorizedValidator
+    )]
+    pub pool_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = user_token_account.owner == user.key() @ NovaError::UnauthorizedValidator,
+        constraint = user_token_account.mint == pool_vault.mint @ NovaError::InvalidPremiumAmount
+    )]
+    pub user_token_account: Box<Account<'info, TokenAccount>>,
+
     #[account(mut)]
     pub user: Signer<'info>,
 
     pub token_program: Program<'info, Token>,
 }
 
+#[derive(Accounts)]
+pub struct LapseCoverage<'info> {
+    #[account(mut)]
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    #[account(
+        mut,
+        seeds = [b"coverage", user_coverage.user.as_ref(), pool.key().as_ref()],
+        bump = user_coverage.bump,
+        constraint = user_coverage.pool == pool.key() @ NovaError::InactiveCoverage
+    )]
+    pub user_coverage: Box<Account<'info, UserCoverage>>,
+}
+
+#[derive(Accounts)]
+pub struct ProposePoolConfig<'info> {
+    #[account(
+        constraint = pool.authority == authority.key() @ NovaError::Unauthorized
+    )]
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + PendingPoolConfig::INIT_SPACE,
+        seeds = [b"pending_config", pool.key().as_ref()],
+        bump
+    )]
+    pub pending_config: Box<Account<'info, PendingPoolConfig>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExecutePoolConfig<'info> {
+    #[account(
+        mut,
+        constraint = pool.authority == authority.key() @ NovaError::Unauthorized
+    )]
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"pending_config", pool.key().as_ref()],
+        bump = pending_config.bump,
+        constraint = pending_config.pool == pool.key() @ NovaError::Unauthorized
+    )]
+    pub pending_config: Box<Account<'info, PendingPoolConfig>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CancelPoolConfig<'info> {
+    #[account(
+        constraint = pool.authority == authority.key() @ NovaError::Unauthorized
+    )]
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"pending_config", pool.key().as_ref()],
+        bump = pending_config.bump,
+        constraint = pending_config.pool == pool.key() @ NovaError::Unauthorized
+    )]
+    pub pending_config: Box<Account<'info, PendingPoolConfig>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeAuthorityTransfer<'info> {
+    #[account(
+        mut,
+        constraint = pool.authority == authority.key() @ NovaError::Unauthorized
+    )]
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAuthorityTransfer<'info> {
+    #[account(
+        mut,
+        constraint = pool.pending_authority.is_some() @ NovaError::NoPendingAuthority,
+        constraint = pool.pending_authority == Some(new_authority.key()) @ NovaError::Unauthorized
+    )]
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    pub new_authority: Signer<'info>,
+}
+
 // ============================================================================
 // Events
 // ============================================================================
@@ -292,6 +1232,7 @@ pub struct PoolCreatedEvent {
     pub pool_id: Pubkey,
     pub authority: Pubkey,
     pub pool_type: PoolType,
+    pub pool_index: u8,
     pub premium_amount: u64,
     pub coverage_amount: u64,
     pub min_validators: u8,
@@ -305,6 +1246,7 @@ pub struct UserJoinedEvent {
     pub pool: Pubkey,
     pub coverage_amount: u64,
     pub premium_paid: u64,
+    pub paid_through: i64,
     pub timestamp: i64,
 }
 
@@ -313,6 +1255,83 @@ pub struct PremiumPaidEvent {
     pub user: Pubkey,
     pub pool: Pubkey,
     pub amount: u64,
+    pub periods: u8,
+    pub discount: u64,
     pub total_paid: u64,
+    pub paid_through: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PoolConfigProposedEvent {
+    pub pool: Pubkey,
+    pub current: PoolConfigParams,
+    pub proposed: PoolConfigParams,
+    pub executable_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PoolConfigUpdatedEvent {
+    pub pool: Pubkey,
+    pub params: PoolConfigParams,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PoolConfigCancelledEvent {
+    pub pool: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UserLeftEvent {
+    pub user: Pubkey,
+    pub pool: Pubkey,
+    pub refund: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CoverageLapsedEvent {
+    pub user: Pubkey,
+    pub pool: Pubkey,
+    pub paid_through: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CoverageUpdatedEvent {
+    pub user: Pubkey,
+    pub pool: Pubkey,
+    pub old_coverage_amount: u64,
+    pub new_coverage_amount: u64,
+    pub premium_per_period: u64,
+    pub amount_charged: u64,
+    pub amount_refunded: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MemberRiskTierUpdatedEvent {
+    pub user: Pubkey,
+    pub pool: Pubkey,
+    pub risk_tier: RiskTier,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferProposedEvent {
+    pub pool: Pubkey,
+    pub current_authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferredEvent {
+    pub pool: Pubkey,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
     pub timestamp: i64,
 }
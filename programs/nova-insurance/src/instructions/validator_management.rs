@@ -1,7 +1,11 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::{transfer, Transfer as SystemTransfer};
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
 use crate::errors::*;
+use crate::instructions::vrf_integration::{
+    generate_randomness, select_available_validators, select_random_validators,
+};
 use crate::state::*;
 
 /// Initialize validator registry for a pool
@@ -14,6 +18,7 @@ pub fn initialize_validator_registry(
     validator_registry.pool = pool.key();
     validator_registry.validators = Vec::new();
     validator_registry.total_validators = 0;
+    validator_registry.assignments = Vec::new();
     validator_registry.bump = *ctx.bumps.get("validator_registry").unwrap();
 
     msg!(
@@ -24,6 +29,44 @@ pub fn initialize_validator_registry(
     Ok(())
 }
 
+/// Initialize the slashing treasury that collects slashed validator stake for a pool
+pub fn initialize_slashing_treasury(ctx: Context<InitializeSlashingTreasury>) -> Result<()> {
+    let treasury = &mut ctx.accounts.slashing_treasury;
+    let pool = &ctx.accounts.pool;
+
+    treasury.pool = pool.key();
+    treasury.total_slashed = 0;
+    treasury.total_redistributed = 0;
+    treasury.bump = *ctx.bumps.get("slashing_treasury").unwrap();
+
+    msg!(
+        "Slashing treasury initialized for pool {}",
+        pool.key()
+    );
+
+    Ok(())
+}
+
+/// Initialize the USDC vault that holds validator rewards funded from premiums
+pub fn initialize_validator_rewards(ctx: Context<InitializeValidatorRewards>) -> Result<()> {
+    let validator_rewards = &mut ctx.accounts.validator_rewards;
+    let pool = &ctx.accounts.pool;
+
+    validator_rewards.pool = pool.key();
+    validator_rewards.vault = ctx.accounts.validator_rewards_vault.key();
+    validator_rewards.total_funded = 0;
+    validator_rewards.total_allocated = 0;
+    validator_rewards.total_claimed = 0;
+    validator_rewards.bump = *ctx.bumps.get("validator_rewards").unwrap();
+
+    msg!(
+        "Validator rewards vault initialized for pool {}",
+        pool.key()
+    );
+
+    Ok(())
+}
+
 /// Stake SOL to become a validator
 pub fn stake_as_validator(
     ctx: Context<StakeAsValidator>,
@@ -32,11 +75,10 @@ pub fn stake_as_validator(
     let pool = &ctx.accounts.pool;
     let clock = Clock::get()?;
 
-    // Validate minimum stake requirement (0.1 SOL minimum)
-    const MIN_STAKE: u64 = 100_000_000; // 0.1 SOL in lamports
+    // Validate the pool's minimum stake requirement
     require!(
-        stake_amount >= MIN_STAKE,
-        NovaError::InsufficientValidators
+        stake_amount >= pool.min_validator_stake,
+        NovaError::InsufficientStake
     );
 
     // Get keys before mutation
@@ -60,23 +102,17 @@ pub fn stake_as_validator(
     validator_stake.successful_validations = 0;
     validator_stake.reputation_score = ValidatorStake::INITIAL_REPUTATION;
     validator_stake.last_validation = 0;
+    validator_stake.unstake_requested_at = None;
+    validator_stake.pending_rewards = 0;
+    validator_stake.rewards_claimed = 0;
+    validator_stake.linked_wallets = Vec::new();
     validator_stake.bump = *ctx.bumps.get("validator_stake").unwrap();
 
     // Register validator in pool's validator registry
     let validator_registry = &mut ctx.accounts.validator_registry;
     
     // Add validator to registry if not already present
-    if !validator_registry.validators.contains(&validator_key) {
-        require!(
-            validator_registry.validators.len() < validator_registry.validators.capacity(),
-            NovaError::InsufficientValidators
-        );
-        validator_registry.validators.push(validator_key);
-        validator_registry.total_validators = validator_registry
-            .total_validators
-            .checked_add(1)
-            .ok_or(NovaError::InvalidCoverageAmount)?;
-    }
+    validator_registry.add_validator(validator_stake)?;
 
     emit!(ValidatorStakedEvent {
         validator: validator_key,
@@ -96,19 +132,332 @@ pub fn stake_as_validator(
     Ok(())
 }
 
-/// Validate a claim (approve or reject)
+/// Add SOL to an existing validator stake. A validator that fell below the pool's
+/// minimum stake becomes selectable again once topped back up to it.
+pub fn add_stake(ctx: Context<AddStake>, amount: u64) -> Result<()> {
+    let pool = &ctx.accounts.pool;
+    let clock = Clock::get()?;
+
+    require!(amount > 0, NovaError::InsufficientStake);
+    require!(
+        ctx.accounts.validator_stake.unstake_requested_at.is_none(),
+        NovaError::UnstakeAlreadyRequested
+    );
+
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        SystemTransfer {
+            from: ctx.accounts.validator.to_account_info(),
+            to: ctx.accounts.validator_stake.to_account_info(),
+        },
+    );
+    transfer(transfer_ctx, amount)?;
+
+    let validator_stake = &mut ctx.accounts.validator_stake;
+    validator_stake.stake_amount = validator_stake
+        .stake_amount
+        .checked_add(amount)
+        .ok_or(NovaError::MathOverflow)?;
+
+    let eligible = validator_stake.stake_amount >= pool.min_validator_stake;
+    if eligible {
+        ctx.accounts
+            .validator_registry
+            .add_validator(validator_stake)?;
+    } else {
+        ctx.accounts.validator_registry.sync_validator(validator_stake);
+    }
+
+    emit!(StakeAddedEvent {
+        validator: validator_stake.validator,
+        pool: pool.key(),
+        amount,
+        stake_amount: validator_stake.stake_amount,
+        eligible,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Validator {} added {} lamports, stake now {}",
+        validator_stake.validator,
+        amount,
+        validator_stake.stake_amount
+    );
+
+    Ok(())
+}
+
+/// Request to stop validating for a pool. The validator leaves the registry
+/// immediately and can withdraw their stake once the pool's cooldown has passed.
+pub fn request_unstake(ctx: Context<RequestUnstake>) -> Result<()> {
+    let pool = &ctx.accounts.pool;
+    let validator_stake = &mut ctx.accounts.validator_stake;
+    let validator_registry = &mut ctx.accounts.validator_registry;
+    let clock = Clock::get()?;
+
+    require!(
+        validator_stake.unstake_requested_at.is_none(),
+        NovaError::UnstakeAlreadyRequested
+    );
+    let validator_key = validator_stake.validator;
+    require!(
+        validator_registry.open_claims(&validator_key) == 0,
+        NovaError::ValidatorHasOpenClaims
+    );
+
+    // Leave the registry so no further claims are assigned
+    validator_registry.remove_validator(&validator_key);
+    validator_stake.unstake_requested_at = Some(clock.unix_timestamp);
+
+    let withdrawable_at = clock
+        .unix_timestamp
+        .checked_add(pool.unstake_cooldown)
+        .ok_or(NovaError::MathOverflow)?;
+
+    emit!(UnstakeRequestedEvent {
+        validator: validator_key,
+        pool: pool.key(),
+        stake_amount: validator_stake.stake_amount,
+        withdrawable_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Validator {} requested to unstake from pool {}, withdrawable at {}",
+        validator_key,
+        pool.key(),
+        withdrawable_at
+    );
+
+    Ok(())
+}
+
+/// Withdraw the full stake after the unstake cooldown, closing the stake account
+pub fn withdraw_stake(ctx: Context<WithdrawStake>) -> Result<()> {
+    let pool = &ctx.accounts.pool;
+    let validator_stake = &ctx.accounts.validator_stake;
+    let clock = Clock::get()?;
+
+    let requested_at = validator_stake
+        .unstake_requested_at
+        .ok_or(NovaError::UnstakeNotRequested)?;
+    let withdrawable_at = requested_at
+        .checked_add(pool.unstake_cooldown)
+        .ok_or(NovaError::MathOverflow)?;
+    require!(
+        clock.unix_timestamp >= withdrawable_at,
+        NovaError::UnstakeCooldownActive
+    );
+    require!(
+        ctx.accounts
+            .validator_registry
+            .open_claims(&validator_stake.validator)
+            == 0,
+        NovaError::ValidatorHasOpenClaims
+    );
+
+    require!(
+        validator_stake.pending_rewards == 0,
+        NovaError::UnclaimedValidatorRewards
+    );
+
+    // Closing the account returns the stake together with its rent reserve
+    let amount = validator_stake.to_account_info().lamports();
+
+    emit!(StakeWithdrawnEvent {
+        validator: validator_stake.validator,
+        pool: pool.key(),
+        stake_amount: validator_stake.stake_amount,
+        lamports_returned: amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Validator {} withdrew {} lamports from pool {}",
+        validator_stake.validator,
+        amount,
+        pool.key()
+    );
+
+    Ok(())
+}
+
+/// Declare the wallets a validator controls or is closely linked to, replacing any
+/// earlier declaration. The validator can recuse from claims filed by these wallets.
+pub fn declare_linked_wallets(
+    ctx: Context<DeclareLinkedWallets>,
+    wallets: Vec<Pubkey>,
+) -> Result<()> {
+    let validator_stake = &mut ctx.accounts.validator_stake;
+    let clock = Clock::get()?;
+
+    require!(
+        wallets.len() <= ValidatorStake::MAX_LINKED_WALLETS,
+        NovaError::InvalidLinkedWallets
+    );
+    for (i, wallet) in wallets.iter().enumerate() {
+        require!(
+            *wallet != validator_stake.validator && !wallets[..i].contains(wallet),
+            NovaError::InvalidLinkedWallets
+        );
+    }
+
+    validator_stake.linked_wallets = wallets.clone();
+
+    emit!(LinkedWalletsDeclaredEvent {
+        validator: validator_stake.validator,
+        pool: ctx.accounts.pool.key(),
+        wallets,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Validator {} declared {} linked wallets",
+        validator_stake.validator,
+        validator_stake.linked_wallets.len()
+    );
+
+    Ok(())
+}
+
+/// Claim the USDC rewards a validator has earned from settled validations
+pub fn claim_validator_rewards(ctx: Context<ClaimValidatorRewards>) -> Result<()> {
+    let pool = &ctx.accounts.pool;
+    let clock = Clock::get()?;
+
+    let amount = ctx.accounts.validator_stake.pending_rewards;
+    require!(amount > 0, NovaError::NoValidatorRewards);
+
+    let seeds = pool.signer_seeds();
+    let signer = &[&seeds[..]];
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.validator_rewards_vault.to_account_info(),
+            to: ctx.accounts.validator_token_account.to_account_info(),
+            authority: pool.to_account_info(),
+        },
+        signer,
+    );
+    token::transfer(transfer_ctx, amount)?;
+
+    let validator_stake = &mut ctx.accounts.validator_stake;
+    validator_stake.pending_rewards = 0;
+    validator_stake.rewards_claimed = validator_stake
+        .rewards_claimed
+        .checked_add(amount)
+        .ok_or(NovaError::MathOverflow)?;
+
+    let validator_rewards = &mut ctx.accounts.validator_rewards;
+    validator_rewards.total_claimed = validator_rewards
+        .total_claimed
+        .checked_add(amount)
+        .ok_or(NovaError::MathOverflow)?;
+
+    emit!(ValidatorRewardsClaimedEvent {
+        validator: validator_stake.validator,
+        pool: pool.key(),
+        amount,
+        total_claimed: validator_stake.rewards_claimed,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Validator {} claimed {} USDC in rewards",
+        validator_stake.validator,
+        amount
+    );
+
+    Ok(())
+}
+
+/// Commit a hashed vote on a claim during the commit phase. The commitment is
+/// `ClaimRequest::vote_hash(validator, approve, approved_amount, salt)`, i.e.
+/// `hash(approve as u8 || approved_amount (u64 LE) || salt || validator)`; the
+/// vote stays hidden until it is revealed through `validate_claim`.
+pub fn commit_vote(ctx: Context<CommitVote>, commitment: [u8; 32]) -> Result<()> {
+    let claim = &mut ctx.accounts.claim_request;
+    let pool = &ctx.accounts.pool;
+    let clock = Clock::get()?;
+
+    pool.require_not_paused(PauseCategory::VALIDATION)?;
+
+    require!(
+        claim.status == ClaimStatus::UnderValidation,
+        NovaError::ClaimPeriodExpired
+    );
+
+    let validator_key = ctx.accounts.validator.key();
+    require!(
+        claim.validators_assigned.contains(&validator_key),
+        NovaError::UnauthorizedValidator
+    );
+    require!(
+        clock.unix_timestamp <= claim.voting_deadline,
+        NovaError::CommitDeadlinePassed
+    );
+
+    // Pool members must recuse instead when the pool bars them from judging
+    require!(
+        !pool.bar_member_validators || ctx.accounts.validator_coverage.data_is_empty(),
+        NovaError::ValidatorIsPoolMember
+    );
+    require!(
+        !claim.commitments.iter().any(|c| c.validator == validator_key),
+        NovaError::DuplicateValidation
+    );
+
+    claim.commitments.push(VoteCommitment {
+        validator: validator_key,
+        commitment,
+        revealed: false,
+    });
+
+    // The reveal phase opens once every assigned validator has committed
+    if claim.all_committed() {
+        claim.reveal_deadline = clock
+            .unix_timestamp
+            .checked_add(pool.voting_period)
+            .ok_or(NovaError::MathOverflow)?;
+    }
+
+    emit!(VoteCommittedEvent {
+        claim_id: claim.claim_id,
+        validator: validator_key,
+        commitments: claim.commitments.len() as u8,
+        reveal_deadline: claim.reveal_deadline,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Validator {} committed vote on claim {} ({}/{})",
+        validator_key,
+        claim.claim_id,
+        claim.commitments.len(),
+        claim.validators_assigned.len()
+    );
+
+    Ok(())
+}
+
+/// Validate a claim (approve or reject) by revealing a committed vote. Approving
+/// votes carry the amount the validator considers covered, up to the amount requested.
 pub fn validate_claim(
     ctx: Context<ValidateClaim>,
     approve: bool,
+    approved_amount: u64,
     reason: String,
+    salt: [u8; 32],
 ) -> Result<()> {
     let claim = &mut ctx.accounts.claim_request;
-    let pool = &ctx.accounts.pool;
+    let pool = &mut ctx.accounts.pool;
     let clock = Clock::get()?;
 
+    pool.require_not_paused(PauseCategory::VALIDATION)?;
+
     // Verify claim is in validation status
     require!(
-        claim.status == ClaimStatus::UnderValidation || claim.status == ClaimStatus::Pending,
+        claim.status == ClaimStatus::UnderValidation,
         NovaError::ClaimPeriodExpired
     );
 
@@ -119,11 +468,36 @@ pub fn validate_claim(
         NovaError::UnauthorizedValidator
     );
 
-    // Check if validator already validated
-    let already_validated = claim.validations
-        .iter()
-        .any(|v| v.validator == validator_key);
-    require!(!already_validated, NovaError::DuplicateValidation);
+    // Votes can only be revealed once every assigned validator has committed
+    require!(claim.reveal_deadline > 0, NovaError::RevealPhaseNotStarted);
+    require!(
+        clock.unix_timestamp <= claim.reveal_deadline,
+        NovaError::RevealDeadlinePassed
+    );
+
+    // Check the reveal against the validator's commitment
+    let commitment = claim
+        .commitments
+        .iter_mut()
+        .find(|c| c.validator == validator_key)
+        .ok_or(NovaError::NoVoteCommitment)?;
+    require!(!commitment.revealed, NovaError::DuplicateValidation);
+    require!(
+        commitment.commitment
+            == ClaimRequest::vote_hash(&validator_key, approve, approved_amount, &salt),
+        NovaError::InvalidVoteReveal
+    );
+    commitment.revealed = true;
+
+    // Approvals propose a covered amount; rejections carry none
+    if approve {
+        require!(
+            approved_amount > 0 && approved_amount <= claim.amount_requested,
+            NovaError::InvalidApprovedAmount
+        );
+    } else {
+        require!(approved_amount == 0, NovaError::InvalidApprovedAmount);
+    }
 
     // Validate reason length
     require!(
@@ -135,8 +509,10 @@ pub fn validate_claim(
     claim.validations.push(Validation {
         validator: validator_key,
         approved: approve,
+        approved_amount,
         reason: reason.clone(),
         timestamp: clock.unix_timestamp,
+        settled: false,
     });
 
     // Update counts
@@ -146,53 +522,35 @@ pub fn validate_claim(
         claim.rejections = claim.rejections.checked_add(1).ok_or(NovaError::InvalidCoverageAmount)?;
     }
 
-    // Check if all validators have responded
-    let total_validations = claim.approvals.checked_add(claim.rejections).ok_or(NovaError::InvalidCoverageAmount)?;
-    let required_validations = claim.validators_assigned.len() as u8;
-
-    // Determine if claim is finalized
-    let is_finalized = total_validations >= required_validations;
-    let majority_threshold = (required_validations / 2) + 1;
-    let is_approved = claim.approvals >= majority_threshold;
+    // Finalize once every committed vote has been revealed
+    let is_finalized = claim.validations.len() >= claim.commitments.len();
+    let is_approved = claim.approvals > claim.rejections;
 
     if is_finalized {
-        if is_approved {
-            claim.status = ClaimStatus::Approved;
-            claim.resolved_at = Some(clock.unix_timestamp);
-            claim.payout_amount = Some(claim.amount_requested);
-            msg!("Claim {} APPROVED", claim.claim_id);
-        } else {
-            claim.status = ClaimStatus::Rejected;
-            claim.resolved_at = Some(clock.unix_timestamp);
-            msg!("Claim {} REJECTED", claim.claim_id);
-        }
-
-        // Update validator reputation based on whether they voted with majority
-        let voted_with_majority = (is_approved && approve) || (!is_approved && !approve);
-        update_validator_reputation(
-            &mut ctx.accounts.validator_stake,
-            voted_with_majority,
+        finalize_claim_decision(
+            claim,
             pool,
+            &mut ctx.accounts.claimant_coverage,
+            is_approved,
+            clock.unix_timestamp,
         )?;
-    } else {
-        // Still waiting for more validations
-        claim.status = ClaimStatus::UnderValidation;
-        
-        // Update validator stats
-        ctx.accounts.validator_stake.validations_completed = ctx.accounts.validator_stake
-            .validations_completed
-            .checked_add(1)
-            .ok_or(NovaError::InvalidCoverageAmount)?;
-        ctx.accounts.validator_stake.last_validation = clock.unix_timestamp;
     }
 
+    // Reputation is applied per validator by settle_validation once the claim is decided
+    ctx.accounts.validator_stake.last_validation = clock.unix_timestamp;
+
     emit!(ClaimValidatedEvent {
         claim_id: claim.claim_id,
         validator: validator_key,
         approved: approve,
+        approved_amount,
         claim_status: claim.status,
         approvals: claim.approvals,
         rejections: claim.rejections,
+        payout_amount: claim.payout_amount,
+        deductible_applied: claim.deductible_applied,
+        coinsurance_applied: claim.coinsurance_applied,
+        annual_limit_applied: claim.annual_limit_applied,
         timestamp: clock.unix_timestamp,
     });
 
@@ -207,71 +565,687 @@ pub fn validate_claim(
     Ok(())
 }
 
-/// Update validator reputation and stats based on voting outcome
-fn update_validator_reputation(
-    validator_stake: &mut ValidatorStake,
-    voted_with_majority: bool,
-    pool: &InsurancePool,
+/// Finalize a claim after the reveal deadline, tallying only the revealed votes.
+/// Permissionless crank; the `ValidatorStake` account of each validator that
+/// committed but did not reveal must be passed as remaining accounts, in commit
+/// order, so the non-reveal penalty can be applied. If no vote was revealed a
+/// fresh panel is drawn instead.
+pub fn finalize_claim_validation<'info>(
+    ctx: Context<'_, '_, '_, 'info, FinalizeClaimValidation<'info>>,
 ) -> Result<()> {
-    // Update validation count
-    validator_stake.validations_completed = validator_stake
-        .validations_completed
-        .checked_add(1)
-        .ok_or(NovaError::InvalidCoverageAmount)?;
-    validator_stake.last_validation = Clock::get()?.unix_timestamp;
+    let claim = &mut ctx.accounts.claim_request;
+    let pool = &mut ctx.accounts.pool;
+    let clock = Clock::get()?;
 
-    if voted_with_majority {
-        // Reward for correct vote
-        validator_stake.successful_validations = validator_stake
-            .successful_validations
-            .checked_add(1)
-            .ok_or(NovaError::InvalidCoverageAmount)?;
-        
-        // Increase reputation
-        validator_stake.reputation_score = validator_stake
-            .reputation_score
-            .saturating_add(100)
-            .min(ValidatorStake::MAX_REPUTATION);
+    pool.require_not_paused(PauseCategory::VALIDATION)?;
 
-        msg!("Validator {} rewarded: +100 reputation", validator_stake.validator);
-    } else {
-        // Slash for incorrect vote
-        slash_validator(validator_stake, pool)?;
-    }
+    require!(
+        claim.status == ClaimStatus::UnderValidation,
+        NovaError::ClaimPeriodExpired
+    );
+    require!(claim.reveal_deadline > 0, NovaError::RevealPhaseNotStarted);
+    require!(
+        clock.unix_timestamp > claim.reveal_deadline,
+        NovaError::RevealDeadlineNotReached
+    );
 
-    Ok(())
-}
+    let non_revealers: Vec<Pubkey> = claim
+        .commitments
+        .iter()
+        .filter(|c| !c.revealed)
+        .map(|c| c.validator)
+        .collect();
+    penalize_validators(
+        &non_revealers,
+        ctx.remaining_accounts,
+        &mut ctx.accounts.validator_registry,
+        &pool.key(),
+        ctx.program_id,
+        ValidatorStake::NON_REVEAL_PENALTY,
+    )?;
 
-/// Slash validator for dishonest behavior
-fn slash_validator(validator_stake: &mut ValidatorStake, pool: &InsurancePool) -> Result<()> {
-    // Calculate slash amount based on pool's minimum validators requirement
-    // Higher requirement = more severe slashing
-    let slash_percentage = pool.min_validators as u32 * 2; // 2% per min validator
-    let slash_amount = (validator_stake.stake_amount as u128)
-        .checked_mul(slash_percentage as u128)
-        .ok_or(NovaError::InvalidCoverageAmount)?
-        .checked_div(100)
-        .ok_or(NovaError::InvalidCoverageAmount)? as u64;
+    if claim.validations.is_empty() {
+        // Nobody revealed: start over with a panel of validators not yet involved
+        let mut excluded = claim.validators_assigned.clone();
+        excluded.extend_from_slice(&claim.original_validators);
+        excluded.extend(claim.conflicted_validators());
+        let randomness = generate_randomness(
+            &claim.key(),
+            &pool.key(),
+            clock.unix_timestamp,
+            clock.slot,
+        );
+        let panel = select_random_validators(
+            &randomness,
+            &ctx.accounts.validator_registry,
+            pool,
+            &excluded,
+            claim.validators_assigned.len(),
+        )?;
+        let validator_registry = &mut ctx.accounts.validator_registry;
+        validator_registry.release_assignments(&non_revealers);
+        validator_registry.record_assignments(&panel)?;
 
-    // Deduct from reputation
-    validator_stake.reputation_score = validator_stake
-        .reputation_score
-        .saturating_sub(200); // -200 reputation for incorrect vote
+        let replaced = std::mem::replace(&mut claim.validators_assigned, panel.clone());
+        claim.vrf_result = Some(randomness);
+        claim.commitments = Vec::new();
+        claim.reveal_deadline = 0;
+        claim.voting_deadline = clock
+            .unix_timestamp
+            .checked_add(pool.voting_period)
+            .ok_or(NovaError::MathOverflow)?;
 
-    // Record slashed amount (actual SOL slashing would be in separate instruction)
-    validator_stake.stake_amount = validator_stake
-        .stake_amount
-        .saturating_sub(slash_amount);
+        emit!(ValidatorsReplacedEvent {
+            claim_id: claim.key(),
+            replaced,
+            replacements: panel,
+            voting_deadline: claim.voting_deadline,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("No votes revealed on claim {}; new panel drawn", claim.key());
+        return Ok(());
+    }
+
+    ctx.accounts
+        .validator_registry
+        .release_assignments(&non_revealers);
+
+    let is_approved = claim.approvals > claim.rejections;
+    finalize_claim_decision(
+        claim,
+        pool,
+        &mut ctx.accounts.claimant_coverage,
+        is_approved,
+        clock.unix_timestamp,
+    )?;
 
     msg!(
-        "Validator {} slashed {} lamports and -200 reputation",
-        validator_stake.validator,
-        slash_amount
+        "Claim {} finalized from {} revealed votes ({} not revealed)",
+        claim.key(),
+        claim.validations.len(),
+        non_revealers.len()
     );
 
     Ok(())
 }
 
+/// Resolve a claim once its vote is decided: compute the payout on approval,
+/// capped at the claimant's remaining annual aggregate, release the claimant's
+/// open-claim slot on rejection, and settle any appeal
+fn finalize_claim_decision(
+    claim: &mut Account<ClaimRequest>,
+    pool: &mut Account<InsurancePool>,
+    claimant_coverage: &mut UserCoverage,
+    is_approved: bool,
+    now: i64,
+) -> Result<()> {
+    pool.claims_under_validation = pool.claims_under_validation.saturating_sub(1);
+    claimant_coverage.roll_policy_year(now);
+
+    if is_approved {
+        claim.status = ClaimStatus::Approved;
+        claim.resolved_at = Some(now);
+
+        // Settle on the median approved amount, then apply the pool's
+        // deductible, coinsurance and per-claim cap
+        claim.approved_amount = claim.median_approved_amount();
+        let mut payout = pool.calculate_payout(claim.approved_amount)?;
+
+        // The payout replaces the requested amount in the annual aggregate,
+        // limited to what the member has left of it this policy year
+        claim.annual_limit_applied = 0;
+        if claim.created_at >= claimant_coverage.policy_year_start {
+            let claimed_elsewhere = claimant_coverage
+                .claimed_this_year
+                .saturating_sub(claim.amount_requested);
+            let annual_remaining = pool
+                .annual_limit_for(claimant_coverage.coverage_amount)
+                .saturating_sub(claimed_elsewhere);
+            claim.annual_limit_applied = payout.payout_amount.saturating_sub(annual_remaining);
+            payout.payout_amount -= claim.annual_limit_applied;
+            claimant_coverage.claimed_this_year = claimed_elsewhere
+                .checked_add(payout.payout_amount)
+                .ok_or(NovaError::MathOverflow)?;
+        }
+
+        claim.payout_amount = Some(payout.payout_amount);
+        claim.deductible_applied = payout.deductible_applied;
+        claim.coinsurance_applied = payout.coinsurance_applied;
+        pool.approved_unpaid_claims = pool
+            .approved_unpaid_claims
+            .checked_add(payout.payout_amount)
+            .ok_or(NovaError::MathOverflow)?;
+        msg!(
+            "Claim {} APPROVED for {} USDC ({} deductible, {} coinsurance, {} over annual limit)",
+            claim.claim_id,
+            payout.payout_amount,
+            payout.deductible_applied,
+            payout.coinsurance_applied,
+            claim.annual_limit_applied
+        );
+    } else {
+        claim.status = ClaimStatus::Rejected;
+        claim.resolved_at = Some(now);
+        if !claim.appealed {
+            pool.last_rejection_at = now;
+        }
+        pool.active_claims = pool.active_claims.saturating_sub(1);
+        claimant_coverage.open_claims = claimant_coverage.open_claims.saturating_sub(1);
+        // Rejected claims no longer count toward the annual aggregate
+        if claim.created_at >= claimant_coverage.policy_year_start {
+            claimant_coverage.claimed_this_year = claimant_coverage
+                .claimed_this_year
+                .saturating_sub(claim.amount_requested);
+        }
+        msg!("Claim {} REJECTED", claim.claim_id);
+    }
+
+    // An appeal panel's decision overrides the first round
+    if claim.appealed {
+        let bond = claim.appeal_bond;
+        if is_approved {
+            claim.appeal_overturned = true;
+        } else {
+            // Failed appeal: the bond stays in the vault as pool funds
+            pool.total_pooled = pool
+                .total_pooled
+                .checked_add(bond)
+                .ok_or(NovaError::MathOverflow)?;
+            pool.appeal_bonds_held = pool.appeal_bonds_held.saturating_sub(bond);
+            claim.appeal_bond = 0;
+            claim.original_majority = Vec::new();
+        }
+
+        emit!(AppealDecidedEvent {
+            claim_id: claim.claim_id,
+            pool: pool.key(),
+            overturned: is_approved,
+            bond,
+            timestamp: now,
+        });
+    }
+
+    Ok(())
+}
+
+/// Apply a reputation penalty to each validator in `validators`, whose
+/// `ValidatorStake` accounts are passed in the same order as `stake_accounts`,
+/// and refresh their selection weight in the registry
+fn penalize_validators<'info>(
+    validators: &[Pubkey],
+    stake_accounts: &[AccountInfo<'info>],
+    validator_registry: &mut ValidatorRegistry,
+    pool_key: &Pubkey,
+    program_id: &Pubkey,
+    penalty: u32,
+) -> Result<()> {
+    require!(
+        stake_accounts.len() == validators.len(),
+        NovaError::UnauthorizedValidator
+    );
+
+    for (validator, info) in validators.iter().zip(stake_accounts.iter()) {
+        let mut validator_stake = Account::<ValidatorStake>::try_from(info)?;
+        let expected = Pubkey::create_program_address(
+            &[
+                b"validator",
+                validator.as_ref(),
+                pool_key.as_ref(),
+                &[validator_stake.bump],
+            ],
+            program_id,
+        )
+        .map_err(|_| NovaError::UnauthorizedValidator)?;
+        require!(
+            info.key() == expected && validator_stake.validator == *validator,
+            NovaError::UnauthorizedValidator
+        );
+
+        validator_stake.reputation_score = validator_stake
+            .reputation_score
+            .saturating_sub(penalty);
+        validator_registry.sync_validator(&validator_stake);
+        validator_stake.exit(program_id)?;
+    }
+
+    Ok(())
+}
+
+/// Replace assigned validators who missed the commit deadline with newly sampled
+/// validators from the registry. Permissionless crank; the `ValidatorStake` account
+/// of each non-responder must be passed as remaining accounts, in assignment order,
+/// so the missed-deadline penalty can be applied. If the registry runs out of
+/// eligible validators, non-responders without a replacement are dropped from the
+/// panel, and once only validators who committed remain the reveal phase opens.
+pub fn replace_unresponsive_validators<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReplaceUnresponsiveValidators<'info>>,
+) -> Result<()> {
+    let claim = &mut ctx.accounts.claim_request;
+    let pool = &ctx.accounts.pool;
+    let clock = Clock::get()?;
+
+    pool.require_not_paused(PauseCategory::VALIDATION)?;
+
+    require!(
+        claim.status == ClaimStatus::UnderValidation,
+        NovaError::ClaimPeriodExpired
+    );
+    require!(
+        clock.unix_timestamp > claim.voting_deadline,
+        NovaError::VotingDeadlineNotReached
+    );
+
+    // Validators that have not committed a vote are unresponsive
+    let unresponsive: Vec<Pubkey> = claim
+        .validators_assigned
+        .iter()
+        .filter(|v| !claim.commitments.iter().any(|c| c.validator == **v))
+        .copied()
+        .collect();
+    require!(!unresponsive.is_empty(), NovaError::NoUnresponsiveValidators);
+
+    let pool_key = pool.key();
+    penalize_validators(
+        &unresponsive,
+        ctx.remaining_accounts,
+        &mut ctx.accounts.validator_registry,
+        &pool_key,
+        ctx.program_id,
+        ValidatorStake::MISSED_DEADLINE_PENALTY,
+    )?;
+
+    // Sample replacements from validators not yet involved with this claim
+    let mut excluded = claim.validators_assigned.clone();
+    excluded.extend_from_slice(&claim.original_validators);
+    excluded.extend(claim.conflicted_validators());
+    let randomness = generate_randomness(
+        &claim.key(),
+        &pool_key,
+        clock.unix_timestamp,
+        clock.slot,
+    );
+    let replacements = select_available_validators(
+        &randomness,
+        &ctx.accounts.validator_registry,
+        pool,
+        &excluded,
+        unresponsive.len(),
+    )?;
+    // With nobody committed and nobody to draw, the claim can still be withdrawn
+    require!(
+        !replacements.is_empty() || !claim.commitments.is_empty(),
+        NovaError::InsufficientValidators
+    );
+    let validator_registry = &mut ctx.accounts.validator_registry;
+    validator_registry.release_assignments(&unresponsive);
+    validator_registry.record_assignments(&replacements)?;
+
+    claim.replace_validators(&unresponsive, &replacements);
+    let dropped = unresponsive.len() - replacements.len();
+    claim.voting_deadline = clock
+        .unix_timestamp
+        .checked_add(pool.voting_period)
+        .ok_or(NovaError::MathOverflow)?;
+    if claim.all_committed() {
+        claim.reveal_deadline = claim.voting_deadline;
+    }
+
+    emit!(ValidatorsReplacedEvent {
+        claim_id: claim.key(),
+        replaced: unresponsive.clone(),
+        replacements,
+        voting_deadline: claim.voting_deadline,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Replaced {} and dropped {} unresponsive validators on claim {}",
+        unresponsive.len() - dropped,
+        dropped,
+        claim.key()
+    );
+
+    Ok(())
+}
+
+/// Recuse an assigned validator from a claim they have a conflict of interest in:
+/// the claimant is one of their declared linked wallets, or they are a member of a
+/// pool that bars members from judging. A replacement is drawn in their place.
+pub fn recuse_from_claim(ctx: Context<RecuseFromClaim>) -> Result<()> {
+    let claim = &mut ctx.accounts.claim_request;
+    let pool = &ctx.accounts.pool;
+    let validator_stake = &ctx.accounts.validator_stake;
+    let clock = Clock::get()?;
+
+    pool.require_not_paused(PauseCategory::VALIDATION)?;
+
+    require!(
+        claim.status == ClaimStatus::UnderValidation,
+        NovaError::ClaimPeriodExpired
+    );
+    let validator_key = validator_stake.validator;
+    let slot = claim
+        .validators_assigned
+        .iter()
+        .position(|v| *v == validator_key)
+        .ok_or(NovaError::UnauthorizedValidator)?;
+    require!(
+        !claim.commitments.iter().any(|c| c.validator == validator_key),
+        NovaError::CannotRecuseAfterCommit
+    );
+
+    let linked = validator_stake.linked_wallets.contains(&claim.claimant);
+    let barred_member =
+        pool.bar_member_validators && !ctx.accounts.validator_coverage.data_is_empty();
+    require!(linked || barred_member, NovaError::NoConflictOfInterest);
+
+    require!(
+        claim.recused.len() < ClaimRequest::MAX_VALIDATORS,
+        NovaError::InsufficientValidators
+    );
+    claim.recused.push(validator_key);
+
+    // Draw a replacement from validators not yet involved with this claim
+    let mut excluded = claim.validators_assigned.clone();
+    excluded.extend_from_slice(&claim.original_validators);
+    excluded.extend(claim.conflicted_validators());
+    let randomness = generate_randomness(
+        &claim.key(),
+        &pool.key(),
+        clock.unix_timestamp,
+        clock.slot,
+    );
+    let replacement = select_random_validators(
+        &randomness,
+        &ctx.accounts.validator_registry,
+        pool,
+        &excluded,
+        1,
+    )?[0];
+
+    let validator_registry = &mut ctx.accounts.validator_registry;
+    validator_registry.release_assignments(&[validator_key]);
+    validator_registry.record_assignments(&[replacement])?;
+
+    claim.validators_assigned[slot] = replacement;
+    claim.voting_deadline = clock
+        .unix_timestamp
+        .checked_add(pool.voting_period)
+        .ok_or(NovaError::MathOverflow)?;
+
+    emit!(ValidatorRecusedEvent {
+        claim_id: claim.key(),
+        validator: validator_key,
+        replacement,
+        linked_wallet: linked,
+        voting_deadline: claim.voting_deadline,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Validator {} recused from claim {}; replaced by {}",
+        validator_key,
+        claim.key(),
+        replacement
+    );
+
+    Ok(())
+}
+
+/// Settle one validator's revealed vote on a decided claim, adjusting reputation
+/// by how close the vote was to the outcome. Votes far from the outcome are slashed
+/// into the pool's slashing treasury; majority voters then receive an equal share of
+/// the redistributable part of that claim's slashes. Permissionless; each vote settles
+/// once, and majority votes settle only after every minority vote on the claim.
+pub fn settle_validation(ctx: Context<SettleValidation>) -> Result<()> {
+    let claim = &mut ctx.accounts.claim_request;
+    let pool = &ctx.accounts.pool;
+    let validator_stake = &mut ctx.accounts.validator_stake;
+    let treasury = &mut ctx.accounts.slashing_treasury;
+    let clock = Clock::get()?;
+
+    require!(
+        matches!(
+            claim.status,
+            ClaimStatus::Approved
+                | ClaimStatus::Rejected
+                | ClaimStatus::Queued
+                | ClaimStatus::Distributed
+        ),
+        NovaError::ClaimNotDecided
+    );
+
+    let position = claim
+        .validations
+        .iter()
+        .position(|v| v.validator == validator_stake.validator)
+        .ok_or(NovaError::UnauthorizedValidator)?;
+    require!(
+        !claim.validations[position].settled,
+        NovaError::ValidationAlreadySettled
+    );
+
+    // Majority membership, and with it slashing, depends only on agreeing with the
+    // outcome; closeness to the settled amount scales reputation and rewards
+    let closeness_bps = claim.vote_closeness_bps(&claim.validations[position]);
+    let in_majority = claim.voted_with_outcome(&claim.validations[position]);
+
+    // Majority rewards are split from the claim's slashes, so those must all be in
+    let majority_count = claim
+        .validations
+        .iter()
+        .filter(|v| claim.voted_with_outcome(v))
+        .count() as u64;
+    if in_majority {
+        require!(
+            claim
+                .validations
+                .iter()
+                .all(|v| v.settled || claim.voted_with_outcome(v)),
+            NovaError::MinoritySettlementPending
+        );
+    }
+    claim.validations[position].settled = true;
+
+    // A settled vote ends the validator's assignment to the claim
+    ctx.accounts
+        .validator_registry
+        .release_assignments(&[validator_stake.validator]);
+
+    update_validator_reputation(validator_stake, in_majority, closeness_bps)?;
+
+    if in_majority {
+        // Credit the USDC validation reward, limited to what premiums have funded.
+        // Pools that never set up validator rewards settle without one.
+        require!(
+            ctx.accounts.validator_rewards.is_some() || pool.validator_reward_bps == 0,
+            NovaError::ValidatorRewardsVaultRequired
+        );
+        if let Some(validator_rewards) = ctx.accounts.validator_rewards.as_mut() {
+            let usdc_reward = validator_stake
+                .validation_reward(pool.validation_reward, closeness_bps)?
+                .min(validator_rewards.unallocated());
+            if usdc_reward > 0 {
+                validator_rewards.total_allocated = validator_rewards
+                    .total_allocated
+                    .checked_add(usdc_reward)
+                    .ok_or(NovaError::MathOverflow)?;
+                validator_stake.pending_rewards = validator_stake
+                    .pending_rewards
+                    .checked_add(usdc_reward)
+                    .ok_or(NovaError::MathOverflow)?;
+
+                msg!(
+                    "Validator {} earned {} USDC validation reward",
+                    validator_stake.validator,
+                    usdc_reward
+                );
+            }
+        }
+
+        let reward = claim
+            .slash_reward_pool
+            .checked_div(majority_count)
+            .unwrap_or(0);
+        if reward > 0 {
+            move_lamports(
+                &treasury.to_account_info(),
+                &ctx.accounts.validator.to_account_info(),
+                reward,
+            )?;
+            treasury.total_redistributed = treasury
+                .total_redistributed
+                .checked_add(reward)
+                .ok_or(NovaError::MathOverflow)?;
+
+            msg!(
+                "Validator {} rewarded {} lamports from slashed stake",
+                validator_stake.validator,
+                reward
+            );
+        }
+    } else {
+        let slash_amount = slash_validator(validator_stake, treasury, pool)?;
+
+        // Validators slashed below the pool minimum are no longer selectable
+        if validator_stake.stake_amount < pool.min_validator_stake {
+            ctx.accounts
+                .validator_registry
+                .remove_validator(&validator_stake.validator);
+            msg!(
+                "Validator {} fell below the minimum stake and left the registry",
+                validator_stake.validator
+            );
+        }
+
+        // Reserve the pool's redistribution share for this claim's majority voters
+        let redistributable = (slash_amount as u128)
+            .checked_mul(pool.slash_redistribution_bps as u128)
+            .ok_or(NovaError::MathOverflow)?
+            .checked_div(BPS_DENOMINATOR as u128)
+            .ok_or(NovaError::MathOverflow)? as u64;
+        claim.slash_reward_pool = claim
+            .slash_reward_pool
+            .checked_add(redistributable)
+            .ok_or(NovaError::MathOverflow)?;
+
+        emit!(ValidatorSlashedEvent {
+            claim_id: claim.claim_id,
+            validator: validator_stake.validator,
+            pool: pool.key(),
+            amount: slash_amount,
+            redistributable,
+            remaining_stake: validator_stake.stake_amount,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    // Selection weights follow the new stake and reputation
+    ctx.accounts.validator_registry.sync_validator(validator_stake);
+
+    emit!(ValidationSettledEvent {
+        claim_id: claim.claim_id,
+        validator: validator_stake.validator,
+        closeness_bps,
+        reputation_score: validator_stake.reputation_score,
+        stake_amount: validator_stake.stake_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Validator {} settled on claim {}: closeness {} bps, reputation {}",
+        validator_stake.validator,
+        claim.claim_id,
+        closeness_bps,
+        validator_stake.reputation_score
+    );
+
+    Ok(())
+}
+
+/// Update validator reputation and stats based on how close a vote was to the outcome
+fn update_validator_reputation(
+    validator_stake: &mut ValidatorStake,
+    in_majority: bool,
+    closeness_bps: u16,
+) -> Result<()> {
+    // Update validation count
+    validator_stake.validations_completed = validator_stake
+        .validations_completed
+        .checked_add(1)
+        .ok_or(NovaError::InvalidCoverageAmount)?;
+
+    validator_stake.apply_vote_reputation(closeness_bps);
+
+    if in_majority {
+        validator_stake.successful_validations = validator_stake
+            .successful_validations
+            .checked_add(1)
+            .ok_or(NovaError::InvalidCoverageAmount)?;
+    }
+
+    Ok(())
+}
+
+/// Slash validator for dishonest behavior, moving the slashed lamports out of the
+/// stake account into the pool's slashing treasury. Returns the amount slashed.
+fn slash_validator<'info>(
+    validator_stake: &mut Account<'info, ValidatorStake>,
+    treasury: &mut Account<'info, SlashingTreasury>,
+    pool: &InsurancePool,
+) -> Result<u64> {
+    // Calculate slash amount based on pool's minimum validators requirement
+    // Higher requirement = more severe slashing
+    let slash_percentage = pool.min_validators as u32 * 2; // 2% per min validator
+    let slash_amount = (validator_stake.stake_amount as u128)
+        .checked_mul(slash_percentage as u128)
+        .ok_or(NovaError::InvalidCoverageAmount)?
+        .checked_div(100)
+        .ok_or(NovaError::InvalidCoverageAmount)? as u64;
+    let slash_amount = slash_amount.min(validator_stake.stake_amount);
+
+    // Only staked lamports are moved; the account keeps its rent-exempt reserve
+    move_lamports(
+        &validator_stake.to_account_info(),
+        &treasury.to_account_info(),
+        slash_amount,
+    )?;
+
+    validator_stake.stake_amount = validator_stake
+        .stake_amount
+        .checked_sub(slash_amount)
+        .ok_or(NovaError::MathOverflow)?;
+    treasury.total_slashed = treasury
+        .total_slashed
+        .checked_add(slash_amount)
+        .ok_or(NovaError::MathOverflow)?;
+
+    msg!(
+        "Validator {} slashed {} lamports",
+        validator_stake.validator,
+        slash_amount
+    );
+
+    Ok(slash_amount)
+}
+
+/// Move lamports out of a program-owned account
+fn move_lamports(from: &AccountInfo, to: &AccountInfo, amount: u64) -> Result<()> {
+    let from_balance = from
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(NovaError::MathOverflow)?;
+    let to_balance = to
+        .lamports()
+        .checked_add(amount)
+        .ok_or(NovaError::MathOverflow)?;
+    **from.try_borrow_mut_lamports()? = from_balance;
+    **to.try_borrow_mut_lamports()? = to_balance;
+
+    Ok(())
+}
+
 // ============================================================================
 // Account Validation Contexts
 // ============================================================================
@@ -283,26 +1257,140 @@ pub struct InitializeValidatorRegistry<'info> {
         payer = authority,
         space = 8 + ValidatorRegistry::INIT_SPACE,
         seeds = [b"validator_registry", pool.key().as_ref()],
-        bump
+        bump
+    )]
+    pub validator_registry: Box<Account<'info, ValidatorRegistry>>,
+
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeSlashingTreasury<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = SlashingTreasury::LEN,
+        seeds = [b"slashing_treasury", pool.key().as_ref()],
+        bump
+    )]
+    pub slashing_treasury: Box<Account<'info, SlashingTreasury>>,
+
+    #[account(
+        constraint = pool.authority == authority.key() @ NovaError::Unauthorized
+    )]
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeValidatorRewards<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = ValidatorRewards::LEN,
+        seeds = [b"validator_rewards", pool.key().as_ref()],
+        bump
+    )]
+    pub validator_rewards: Box<Account<'info, ValidatorRewards>>,
+
+    #[account(
+        init,
+        payer = authority,
+        token::mint = usdc_mint,
+        token::authority = pool,
+        seeds = [b"validator_rewards_vault", pool.key().as_ref()],
+        bump
+    )]
+    pub validator_rewards_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        constraint = pool.authority == authority.key() @ NovaError::Unauthorized
+    )]
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    #[account(
+        constraint = pool_vault.key() == pool.vault @ NovaError::Unauthorized
+    )]
+    pub pool_vault: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: USDC mint address, must match the pool vault's mint
+    #[account(
+        constraint = usdc_mint.key() == pool_vault.mint @ NovaError::InvalidPremiumAmount
+    )]
+    pub usdc_mint: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct StakeAsValidator<'info> {
+    #[account(
+        init,
+        payer = validator,
+        space = 8 + ValidatorStake::INIT_SPACE,
+        seeds = [b"validator", validator.key().as_ref(), pool.key().as_ref()],
+        bump
+    )]
+    pub validator_stake: Box<Account<'info, ValidatorStake>>,
+
+    #[account(
+        mut,
+        seeds = [b"validator_registry", pool.key().as_ref()],
+        bump = validator_registry.bump
+    )]
+    pub validator_registry: Box<Account<'info, ValidatorRegistry>>,
+
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    #[account(mut)]
+    pub validator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AddStake<'info> {
+    #[account(
+        mut,
+        seeds = [b"validator", validator.key().as_ref(), pool.key().as_ref()],
+        bump = validator_stake.bump
+    )]
+    pub validator_stake: Box<Account<'info, ValidatorStake>>,
+
+    #[account(
+        mut,
+        seeds = [b"validator_registry", pool.key().as_ref()],
+        bump = validator_registry.bump
     )]
     pub validator_registry: Box<Account<'info, ValidatorRegistry>>,
 
     pub pool: Box<Account<'info, InsurancePool>>,
 
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub validator: Signer<'info>,
 
     pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct StakeAsValidator<'info> {
+pub struct RequestUnstake<'info> {
     #[account(
-        init,
-        payer = validator,
-        space = 8 + ValidatorStake::INIT_SPACE,
+        mut,
         seeds = [b"validator", validator.key().as_ref(), pool.key().as_ref()],
-        bump
+        bump = validator_stake.bump
     )]
     pub validator_stake: Box<Account<'info, ValidatorStake>>,
 
@@ -315,15 +1403,117 @@ pub struct StakeAsValidator<'info> {
 
     pub pool: Box<Account<'info, InsurancePool>>,
 
+    pub validator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawStake<'info> {
+    #[account(
+        mut,
+        close = validator,
+        seeds = [b"validator", validator.key().as_ref(), pool.key().as_ref()],
+        bump = validator_stake.bump
+    )]
+    pub validator_stake: Box<Account<'info, ValidatorStake>>,
+
+    #[account(
+        seeds = [b"validator_registry", pool.key().as_ref()],
+        bump = validator_registry.bump
+    )]
+    pub validator_registry: Box<Account<'info, ValidatorRegistry>>,
+
+    pub pool: Box<Account<'info, InsurancePool>>,
+
     #[account(mut)]
     pub validator: Signer<'info>,
+}
 
-    pub system_program: Program<'info, System>,
+#[derive(Accounts)]
+pub struct DeclareLinkedWallets<'info> {
+    #[account(
+        mut,
+        seeds = [b"validator", validator.key().as_ref(), pool.key().as_ref()],
+        bump = validator_stake.bump
+    )]
+    pub validator_stake: Box<Account<'info, ValidatorStake>>,
+
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    pub validator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimValidatorRewards<'info> {
+    #[account(
+        mut,
+        seeds = [b"validator", validator.key().as_ref(), pool.key().as_ref()],
+        bump = validator_stake.bump
+    )]
+    pub validator_stake: Box<Account<'info, ValidatorStake>>,
+
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    #[account(
+        mut,
+        seeds = [b"validator_rewards", pool.key().as_ref()],
+        bump = validator_rewards.bump
+    )]
+    pub validator_rewards: Box<Account<'info, ValidatorRewards>>,
+
+    #[account(
+        mut,
+        constraint = validator_rewards_vault.key() == validator_rewards.vault @ NovaError::Unauthorized
+    )]
+    pub validator_rewards_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = validator_token_account.owner == validator.key() @ NovaError::Unauthorized,
+        constraint = validator_token_account.mint == validator_rewards_vault.mint @ NovaError::InvalidPremiumAmount
+    )]
+    pub validator_token_account: Box<Account<'info, TokenAccount>>,
+
+    pub validator: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct CommitVote<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"claim",
+            pool.key().as_ref(),
+            &claim_request.sequence.to_le_bytes()
+        ],
+        bump = claim_request.bump
+    )]
+    pub claim_request: Box<Account<'info, ClaimRequest>>,
+
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    /// CHECK: The validator's coverage PDA in this pool; it only holds data if they are a member
+    #[account(
+        seeds = [b"coverage", validator.key().as_ref(), pool.key().as_ref()],
+        bump
+    )]
+    pub validator_coverage: AccountInfo<'info>,
+
+    pub validator: Signer<'info>,
 }
 
 #[derive(Accounts)]
 pub struct ValidateClaim<'info> {
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [
+            b"claim",
+            pool.key().as_ref(),
+            &claim_request.sequence.to_le_bytes()
+        ],
+        bump = claim_request.bump
+    )]
     pub claim_request: Box<Account<'info, ClaimRequest>>,
 
     #[account(
@@ -334,8 +1524,162 @@ pub struct ValidateClaim<'info> {
     )]
     pub validator_stake: Box<Account<'info, ValidatorStake>>,
 
+    #[account(mut)]
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    #[account(
+        mut,
+        seeds = [b"coverage", claim_request.claimant.as_ref(), pool.key().as_ref()],
+        bump = claimant_coverage.bump
+    )]
+    pub claimant_coverage: Box<Account<'info, UserCoverage>>,
+
+    pub validator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SettleValidation<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"claim",
+            pool.key().as_ref(),
+            &claim_request.sequence.to_le_bytes()
+        ],
+        bump = claim_request.bump
+    )]
+    pub claim_request: Box<Account<'info, ClaimRequest>>,
+
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    #[account(
+        mut,
+        seeds = [b"validator", validator_stake.validator.as_ref(), pool.key().as_ref()],
+        bump = validator_stake.bump
+    )]
+    pub validator_stake: Box<Account<'info, ValidatorStake>>,
+
+    #[account(
+        mut,
+        seeds = [b"slashing_treasury", pool.key().as_ref()],
+        bump = slashing_treasury.bump
+    )]
+    pub slashing_treasury: Box<Account<'info, SlashingTreasury>>,
+
+    #[account(
+        mut,
+        seeds = [b"validator_registry", pool.key().as_ref()],
+        bump = validator_registry.bump
+    )]
+    pub validator_registry: Box<Account<'info, ValidatorRegistry>>,
+
+    /// Required when the pool sets aside a validator reward share of premiums
+    #[account(
+        mut,
+        seeds = [b"validator_rewards", pool.key().as_ref()],
+        bump = validator_rewards.bump
+    )]
+    pub validator_rewards: Option<Box<Account<'info, ValidatorRewards>>>,
+
+    /// CHECK: Wallet of the settled validator; receives any slashing reward
+    #[account(
+        mut,
+        address = validator_stake.validator @ NovaError::UnauthorizedValidator
+    )]
+    pub validator: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct FinalizeClaimValidation<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"claim",
+            pool.key().as_ref(),
+            &claim_request.sequence.to_le_bytes()
+        ],
+        bump = claim_request.bump
+    )]
+    pub claim_request: Box<Account<'info, ClaimRequest>>,
+
+    #[account(mut)]
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    #[account(
+        mut,
+        seeds = [b"coverage", claim_request.claimant.as_ref(), pool.key().as_ref()],
+        bump = claimant_coverage.bump
+    )]
+    pub claimant_coverage: Box<Account<'info, UserCoverage>>,
+
+    #[account(
+        mut,
+        seeds = [b"validator_registry", pool.key().as_ref()],
+        bump = validator_registry.bump,
+        constraint = validator_registry.pool == pool.key() @ NovaError::InactiveCoverage
+    )]
+    pub validator_registry: Box<Account<'info, ValidatorRegistry>>,
+}
+
+#[derive(Accounts)]
+pub struct ReplaceUnresponsiveValidators<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"claim",
+            pool.key().as_ref(),
+            &claim_request.sequence.to_le_bytes()
+        ],
+        bump = claim_request.bump
+    )]
+    pub claim_request: Box<Account<'info, ClaimRequest>>,
+
+    pub pool: Box<Account<'info, InsurancePool>>,
+
+    #[account(
+        mut,
+        seeds = [b"validator_registry", pool.key().as_ref()],
+        bump = validator_registry.bump,
+        constraint = validator_registry.pool == pool.key() @ NovaError::InactiveCoverage
+    )]
+    pub validator_registry: Box<Account<'info, ValidatorRegistry>>,
+}
+
+#[derive(Accounts)]
+pub struct RecuseFromClaim<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"claim",
+            pool.key().as_ref(),
+            &claim_request.sequence.to_le_bytes()
+        ],
+        bump = claim_request.bump
+    )]
+    pub claim_request: Box<Account<'info, ClaimRequest>>,
+
     pub pool: Box<Account<'info, InsurancePool>>,
 
+    #[account(
+        seeds = [b"validator", validator.key().as_ref(), pool.key().as_ref()],
+        bump = validator_stake.bump
+    )]
+    pub validator_stake: Box<Account<'info, ValidatorStake>>,
+
+    #[account(
+        mut,
+        seeds = [b"validator_registry", pool.key().as_ref()],
+        bump = validator_registry.bump
+    )]
+    pub validator_registry: Box<Account<'info, ValidatorRegistry>>,
+
+    /// CHECK: The validator's coverage PDA in this pool; it only holds data if they are a member
+    #[account(
+        seeds = [b"coverage", validator.key().as_ref(), pool.key().as_ref()],
+        bump
+    )]
+    pub validator_coverage: AccountInfo<'info>,
+
     pub validator: Signer<'info>,
 }
 
@@ -352,13 +1696,121 @@ pub struct ValidatorStakedEvent {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct VoteCommittedEvent {
+    pub claim_id: Pubkey,
+    pub validator: Pubkey,
+    pub commitments: u8,
+    pub reveal_deadline: i64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct ClaimValidatedEvent {
     pub claim_id: Pubkey,
     pub validator: Pubkey,
     pub approved: bool,
+    pub approved_amount: u64,
     pub claim_status: ClaimStatus,
     pub approvals: u8,
     pub rejections: u8,
+    pub payout_amount: Option<u64>,
+    pub deductible_applied: u64,
+    pub coinsurance_applied: u64,
+    pub annual_limit_applied: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AppealDecidedEvent {
+    pub claim_id: Pubkey,
+    pub pool: Pubkey,
+    pub overturned: bool,
+    pub bond: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ValidatorsReplacedEvent {
+    pub claim_id: Pubkey,
+    pub replaced: Vec<Pubkey>,
+    pub replacements: Vec<Pubkey>,
+    pub voting_deadline: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ValidationSettledEvent {
+    pub claim_id: Pubkey,
+    pub validator: Pubkey,
+    pub closeness_bps: u16,
+    pub reputation_score: u32,
+    pub stake_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ValidatorSlashedEvent {
+    pub claim_id: Pubkey,
+    pub validator: Pubkey,
+    pub pool: Pubkey,
+    pub amount: u64,
+    pub redistributable: u64,
+    pub remaining_stake: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StakeAddedEvent {
+    pub validator: Pubkey,
+    pub pool: Pubkey,
+    pub amount: u64,
+    pub stake_amount: u64,
+    pub eligible: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ValidatorRewardsClaimedEvent {
+    pub validator: Pubkey,
+    pub pool: Pubkey,
+    pub amount: u64,
+    pub total_claimed: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UnstakeRequestedEvent {
+    pub validator: Pubkey,
+    pub pool: Pubkey,
+    pub stake_amount: u64,
+    pub withdrawable_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StakeWithdrawnEvent {
+    pub validator: Pubkey,
+    pub pool: Pubkey,
+    pub stake_amount: u64,
+    pub lamports_returned: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LinkedWalletsDeclaredEvent {
+    pub validator: Pubkey,
+    pub pool: Pubkey,
+    pub wallets: Vec<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ValidatorRecusedEvent {
+    pub claim_id: Pubkey,
+    pub validator: Pubkey,
+    pub replacement: Pubkey,
+    pub linked_wallet: bool,
+    pub voting_deadline: i64,
     pub timestamp: i64,
 }
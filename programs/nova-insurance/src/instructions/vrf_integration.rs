@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::hash::{hash, hashv};
 
 use crate::errors::*;
 use crate::state::*;
@@ -9,9 +9,10 @@ pub fn initialize_vrf_state(
     ctx: Context<InitializeVrfState>,
 ) -> Result<()> {
     let vrf_state = &mut ctx.accounts.vrf_state;
-    let pool = &ctx.accounts.pool;
+    let pool = &mut ctx.accounts.pool;
     let clock = Clock::get()?;
 
+    pool.vrf_initialized = true;
     vrf_state.pool = pool.key();
     vrf_state.switchboard_vrf = Pubkey::default(); // Will be set when Switchboard is integrated
     vrf_state.authority = ctx.accounts.authority.key();
@@ -34,14 +35,16 @@ pub fn initialize_vrf_state(
 /// Request validator selection (simplified for MVP without Switchboard)
 pub fn request_validator_selection(
     ctx: Context<RequestValidatorSelection>,
-    claim_id: Pubkey,
 ) -> Result<()> {
     let vrf_state = &mut ctx.accounts.vrf_state;
     let claim = &mut ctx.accounts.claim_request;
-    let pool = &ctx.accounts.pool;
-    let validator_registry = &ctx.accounts.validator_registry;
+    let claim_id = claim.key();
+    let pool = &mut ctx.accounts.pool;
+    let validator_registry = &mut ctx.accounts.validator_registry;
     let clock = Clock::get()?;
 
+    pool.require_not_paused(PauseCategory::VALIDATION)?;
+
     // Verify claim is pending and needs validators
     require!(
         claim.status == ClaimStatus::Pending,
@@ -60,12 +63,6 @@ pub fn request_validator_selection(
         NovaError::DuplicateValidation
     );
 
-    // Check we have enough validators in the registry
-    require!(
-        validator_registry.validators.len() >= pool.min_validators as usize,
-        NovaError::InsufficientValidators
-    );
-
     // Generate pseudo-randomness for MVP (deterministic but unpredictable)
     // In production, this would use Switchboard VRF
     let randomness = generate_randomness(
@@ -78,14 +75,27 @@ pub fn request_validator_selection(
     // Select validators using the randomness
     let selected_validators = select_random_validators(
         &randomness,
-        &validator_registry.validators,
+        validator_registry,
+        pool,
+        &claim.conflicted_validators(),
         pool.min_validators as usize,
     )?;
 
     // Assign validators to claim
+    validator_registry.record_assignments(&selected_validators)?;
     claim.validators_assigned = selected_validators.clone();
     claim.status = ClaimStatus::UnderValidation;
     claim.vrf_result = Some(randomness);
+    claim.commitments = Vec::new();
+    claim.reveal_deadline = 0;
+    claim.voting_deadline = clock
+        .unix_timestamp
+        .checked_add(pool.voting_period)
+        .ok_or(NovaError::MathOverflow)?;
+    pool.claims_under_validation = pool
+        .claims_under_validation
+        .checked_add(1)
+        .ok_or(NovaError::MathOverflow)?;
 
     // Update VRF state
     vrf_state.last_randomness = Some(randomness);
@@ -115,7 +125,7 @@ pub fn request_validator_selection(
 /// Generate pseudo-randomness for validator selection
 /// Note: This is deterministic but unpredictable for MVP
 /// Production should use Switchboard VRF for true randomness
-fn generate_randomness(
+pub(crate) fn generate_randomness(
     claim_id: &Pubkey,
     pool_id: &Pubkey,
     timestamp: i64,
@@ -131,44 +141,83 @@ fn generate_randomness(
     hash_result.to_bytes()
 }
 
-/// Select random validators from available pool
-fn select_random_validators(
+/// Select validators from the registry, skipping any in `excluded`. Validators
+/// below the pool's minimum stake or reputation are not eligible; the rest are
+/// sampled without replacement with probability proportional to stake × reputation.
+pub(crate) fn select_random_validators(
     randomness: &[u8; 32],
-    available_validators: &[Pubkey],
+    registry: &ValidatorRegistry,
+    pool: &InsurancePool,
+    excluded: &[Pubkey],
     num_required: usize,
 ) -> Result<Vec<Pubkey>> {
+    let candidates: Vec<&RegisteredValidator> = registry
+        .validators
+        .iter()
+        .filter(|v| !excluded.contains(&v.validator))
+        .filter(|v| v.stake_amount >= pool.min_validator_stake)
+        .collect();
     require!(
-        available_validators.len() >= num_required,
+        candidates.len() >= num_required,
         NovaError::InsufficientValidators
     );
 
-    let mut selected = Vec::new();
-    let mut used_indices = Vec::new();
+    let mut available: Vec<&RegisteredValidator> = candidates
+        .into_iter()
+        .filter(|v| v.reputation_score >= pool.min_validator_reputation && v.weight() > 0)
+        .collect();
+    require!(
+        available.len() >= num_required,
+        NovaError::LowReputation
+    );
 
+    let mut selected = Vec::with_capacity(num_required);
     for i in 0..num_required {
-        // Use different bytes of randomness for each selection
-        let start_byte = (i * 4) % 28; // Ensure we stay within bounds
-        let index_seed = u32::from_le_bytes([
-            randomness[start_byte],
-            randomness[start_byte + 1],
-            randomness[start_byte + 2],
-            randomness[start_byte + 3],
-        ]);
-
-        let mut index = (index_seed as usize) % available_validators.len();
-        
-        // Ensure no duplicates by finding next unused validator
-        while used_indices.contains(&index) {
-            index = (index + 1) % available_validators.len();
+        // Derive an independent draw for each seat from the shared randomness
+        let draw = hashv(&[randomness.as_ref(), &(i as u64).to_le_bytes()]).to_bytes();
+        let total_weight: u128 = available.iter().map(|v| v.weight()).sum();
+        let mut target = u128::from_le_bytes(draw[..16].try_into().unwrap()) % total_weight;
+
+        let mut index = available.len() - 1;
+        for (position, candidate) in available.iter().enumerate() {
+            if target < candidate.weight() {
+                index = position;
+                break;
+            }
+            target -= candidate.weight();
         }
 
-        used_indices.push(index);
-        selected.push(available_validators[index]);
+        selected.push(available.remove(index).validator);
     }
 
     Ok(selected)
 }
 
+/// Like `select_random_validators`, but draws as many eligible validators as the
+/// registry has left, up to `max_required`, instead of failing when it runs short
+pub(crate) fn select_available_validators(
+    randomness: &[u8; 32],
+    registry: &ValidatorRegistry,
+    pool: &InsurancePool,
+    excluded: &[Pubkey],
+    max_required: usize,
+) -> Result<Vec<Pubkey>> {
+    let eligible = registry
+        .validators
+        .iter()
+        .filter(|v| !excluded.contains(&v.validator))
+        .filter(|v| v.stake_amount >= pool.min_validator_stake)
+        .filter(|v| v.reputation_score >= pool.min_validator_reputation && v.weight() > 0)
+        .count();
+    select_random_validators(
+        randomness,
+        registry,
+        pool,
+        excluded,
+        eligible.min(max_required),
+    )
+}
+
 // ============================================================================
 // Account Validation Contexts
 // ============================================================================
@@ -184,6 +233,10 @@ pub struct InitializeVrfState<'info> {
     )]
     pub vrf_state: Box<Account<'info, VrfState>>,
 
+    #[account(
+        mut,
+        constraint = pool.authority == authority.key() @ NovaError::Unauthorized
+    )]
     pub pool: Box<Account<'info, InsurancePool>>,
 
     #[account(mut)]
@@ -202,19 +255,27 @@ pub struct RequestValidatorSelection<'info> {
     )]
     pub vrf_state: Box<Account<'info, VrfState>>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [
+            b"claim",
+            pool.key().as_ref(),
+            &claim_request.sequence.to_le_bytes()
+        ],
+        bump = claim_request.bump
+    )]
     pub claim_request: Box<Account<'info, ClaimRequest>>,
 
+    #[account(mut)]
     pub pool: Box<Account<'info, InsurancePool>>,
 
     #[account(
+        mut,
         seeds = [b"validator_registry", pool.key().as_ref()],
         bump = validator_registry.bump,
         constraint = validator_registry.pool == pool.key() @ NovaError::InactiveCoverage
     )]
     pub validator_registry: Box<Account<'info, ValidatorRegistry>>,
-
-    pub clock: Sysvar<'info, Clock>,
 }
 
 // ============================================================================
@@ -235,3 +296,137 @@ pub struct ValidatorsAssignedEvent {
     pub randomness: [u8; 32],
     pub timestamp: i64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::zeroed;
+
+    fn pool(min_validator_stake: u64, min_validator_reputation: u32) -> InsurancePool {
+        InsurancePool {
+            min_validator_stake,
+            min_validator_reputation,
+            ..zeroed()
+        }
+    }
+
+    fn registry(validators: &[(u64, u32)]) -> ValidatorRegistry {
+        ValidatorRegistry {
+            validators: validators
+                .iter()
+                .map(|&(stake_amount, reputation_score)| RegisteredValidator {
+                    validator: Pubkey::new_unique(),
+                    stake_amount,
+                    reputation_score,
+                })
+                .collect(),
+            total_validators: validators.len() as u32,
+            ..zeroed()
+        }
+    }
+
+    fn randomness(draw: u64) -> [u8; 32] {
+        hash(&draw.to_le_bytes()).to_bytes()
+    }
+
+    #[test]
+    fn selection_is_weighted_by_stake_and_reputation() {
+        // Equal stake, nine times the reputation
+        let registry = registry(&[(1_000, 9_000), (1_000, 1_000)]);
+        let heavy = registry.validators[0].validator;
+        let pool = pool(0, 0);
+
+        let heavy_draws = (0..1_000)
+            .filter(|&i| {
+                select_random_validators(&randomness(i), &registry, &pool, &[], 1).unwrap()
+                    == vec![heavy]
+            })
+            .count();
+        assert!((850..=950).contains(&heavy_draws), "{heavy_draws}");
+    }
+
+    #[test]
+    fn selection_draws_without_replacement() {
+        let registry = registry(&[(1_000, 9_000), (1_000, 100), (1_000, 100)]);
+        let pool = pool(0, 0);
+
+        for i in 0..100 {
+            let mut selected =
+                select_random_validators(&randomness(i), &registry, &pool, &[], 3).unwrap();
+            selected.sort();
+            selected.dedup();
+            assert_eq!(selected.len(), 3);
+        }
+    }
+
+    #[test]
+    fn selection_skips_validators_below_minimum_stake() {
+        let registry = registry(&[(50, 5_000), (1_000, 5_000), (2_000, 5_000)]);
+        let understaked = registry.validators[0].validator;
+        let pool = pool(100, 0);
+
+        for i in 0..100 {
+            let selected =
+                select_random_validators(&randomness(i), &registry, &pool, &[], 2).unwrap();
+            assert!(!selected.contains(&understaked));
+        }
+        assert_eq!(
+            select_random_validators(&randomness(0), &registry, &pool, &[], 3).unwrap_err(),
+            NovaError::InsufficientValidators.into()
+        );
+    }
+
+    #[test]
+    fn selection_reports_low_reputation() {
+        let registry = registry(&[(1_000, 500), (1_000, 5_000)]);
+        let pool = pool(0, 1_000);
+
+        assert_eq!(
+            select_random_validators(&randomness(0), &registry, &pool, &[], 2).unwrap_err(),
+            NovaError::LowReputation.into()
+        );
+    }
+
+    #[test]
+    fn selection_never_picks_excluded_validators() {
+        let registry = registry(&[(1_000, 9_000), (1_000, 1_000), (1_000, 1_000)]);
+        let excluded = [registry.validators[0].validator];
+        let pool = pool(0, 0);
+
+        for i in 0..100 {
+            let selected =
+                select_random_validators(&randomness(i), &registry, &pool, &excluded, 2).unwrap();
+            assert!(!selected.contains(&excluded[0]));
+        }
+    }
+
+    #[test]
+    fn selection_fails_when_exclusions_leave_too_few_validators() {
+        let registry = registry(&[(1_000, 5_000), (1_000, 5_000), (1_000, 5_000)]);
+        let excluded = [registry.validators[0].validator, registry.validators[1].validator];
+        let pool = pool(0, 0);
+
+        assert_eq!(
+            select_random_validators(&randomness(0), &registry, &pool, &excluded, 2).unwrap_err(),
+            NovaError::InsufficientValidators.into()
+        );
+    }
+
+    #[test]
+    fn available_selection_draws_what_an_exhausted_registry_has_left() {
+        let registry = registry(&[(1_000, 5_000), (1_000, 5_000), (1_000, 0), (10, 5_000)]);
+        let excluded = [registry.validators[0].validator];
+        let pool = pool(100, 0);
+
+        let selected =
+            select_available_validators(&randomness(0), &registry, &pool, &excluded, 3).unwrap();
+        assert_eq!(selected, vec![registry.validators[1].validator]);
+
+        let excluded = [registry.validators[0].validator, registry.validators[1].validator];
+        assert!(
+            select_available_validators(&randomness(0), &registry, &pool, &excluded, 3)
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
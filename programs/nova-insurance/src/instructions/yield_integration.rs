@@ -16,7 +16,7 @@ use crate::state::*;
 pub struct DepositToYield<'info> {
     #[account(
         mut,
-        seeds = [b"pool", pool.pool_id.as_ref()],
+        seeds = [b"pool", pool.creator.as_ref(), &[pool.pool_index]],
         bump = pool.bump,
     )]
     pub pool: Box<Account<'info, InsurancePool>>,
@@ -47,7 +47,7 @@ pub struct DepositToYield<'info> {
 pub struct WithdrawFromYield<'info> {
     #[account(
         mut,
-        seeds = [b"pool", pool.pool_id.as_ref()],
+        seeds = [b"pool", pool.creator.as_ref(), &[pool.pool_index]],
         bump = pool.bump,
     )]
     pub pool: Box<Account<'info, InsurancePool>>,
@@ -90,6 +90,9 @@ pub fn deposit_to_yield(ctx: Context<DepositToYield>, amount: u64) -> Result<()>
     let vault = &ctx.accounts.vault;
     let clock = Clock::get()?;
 
+    pool.require_not_paused(PauseCategory::YIELD)?;
+    pool.require_active()?;
+
     // Validate amount
     require!(amount > 0, NovaError::InvalidCoverageAmount);
 
@@ -111,13 +114,8 @@ pub fn deposit_to_yield(ctx: Context<DepositToYield>, amount: u64) -> Result<()>
     );
 
     // Transfer to yield vault using PDA authority
-    let pool_id = pool.pool_id.key();
-    let bump = pool.bump;
-    let signer_seeds: &[&[&[u8]]] = &[&[
-        b"pool",
-        pool_id.as_ref(),
-        &[bump],
-    ]];
+    let seeds = pool.signer_seeds();
+    let signer_seeds: &[&[&[u8]]] = &[&seeds[..]];
 
     let cpi_accounts = Transfer {
         from: ctx.accounts.vault.to_account_info(),
@@ -167,6 +165,8 @@ pub fn withdraw_from_yield(ctx: Context<WithdrawFromYield>, amount: u64) -> Resu
     let pool = &mut ctx.accounts.pool;
     let clock = Clock::get()?;
 
+    pool.require_not_paused(PauseCategory::YIELD)?;
+
     // Validate amount
     require!(amount > 0, NovaError::InvalidCoverageAmount);
     require!(
@@ -6,6 +6,9 @@ pub mod errors;
 pub mod state;
 pub mod instructions;
 
+#[cfg(test)]
+mod test_utils;
+
 #[allow(unused_imports)]
 use errors::*;
 #[allow(unused_imports)]
@@ -16,6 +19,15 @@ use instructions::*;
 pub mod nova_insurance {
     use super::*;
 
+    /// Initialize a page of the directory of pools for a pool type
+    pub fn initialize_pool_directory(
+        ctx: Context<InitializePoolDirectory>,
+        pool_type: PoolType,
+        page: u32,
+    ) -> Result<()> {
+        instructions::initialize_pool_directory(ctx, pool_type, page)
+    }
+
     /// Initialize a new insurance pool
     pub fn initialize_pool(
         ctx: Context<InitializePool>,
@@ -24,6 +36,7 @@ pub mod nova_insurance {
         coverage_amount: u64,
         min_validators: u8,
         claim_period: i64,
+        pool_index: u8,
     ) -> Result<()> {
         instructions::initialize_pool(
             ctx,
@@ -32,6 +45,7 @@ pub mod nova_insurance {
             coverage_amount,
             min_validators,
             claim_period,
+            pool_index,
         )
     }
 
@@ -40,9 +54,50 @@ pub mod nova_insurance {
         instructions::join_pool(ctx, coverage_amount)
     }
 
-    /// Pay monthly premium to maintain coverage
-    pub fn pay_premium(ctx: Context<PayPremium>) -> Result<()> {
-        instructions::pay_premium(ctx)
+    /// Pay premium for one or more billing periods to maintain coverage
+    pub fn pay_premium(ctx: Context<PayPremium>, periods: u8) -> Result<()> {
+        instructions::pay_premium(ctx, periods)
+    }
+
+    /// Change own coverage level with the premium re-quoted
+    pub fn update_coverage(ctx: Context<UpdateCoverage>, new_coverage_amount: u64) -> Result<()> {
+        instructions::update_coverage(ctx, new_coverage_amount)
+    }
+
+    /// Set a member's risk tier for premium pricing
+    pub fn set_member_risk_tier(
+        ctx: Context<SetMemberRiskTier>,
+        risk_tier: RiskTier,
+    ) -> Result<()> {
+        instructions::set_member_risk_tier(ctx, risk_tier)
+    }
+
+    /// Leave a pool and receive the unearned premium back
+    pub fn leave_pool(ctx: Context<LeavePool>) -> Result<()> {
+        instructions::leave_pool(ctx)
+    }
+
+    /// Lapse coverage that is overdue beyond the grace period (permissionless)
+    pub fn lapse_coverage(ctx: Context<LapseCoverage>) -> Result<()> {
+        instructions::lapse_coverage(ctx)
+    }
+
+    /// Queue a timelocked change to pool parameters
+    pub fn propose_pool_config(
+        ctx: Context<ProposePoolConfig>,
+        params: PoolConfigParams,
+    ) -> Result<()> {
+        instructions::propose_pool_config(ctx, params)
+    }
+
+    /// Apply a queued pool config change after its timelock
+    pub fn execute_pool_config(ctx: Context<ExecutePoolConfig>) -> Result<()> {
+        instructions::execute_pool_config(ctx)
+    }
+
+    /// Cancel a queued pool config change
+    pub fn cancel_pool_config(ctx: Context<CancelPoolConfig>) -> Result<()> {
+        instructions::cancel_pool_config(ctx)
     }
 
     /// Submit a new insurance claim
@@ -62,6 +117,40 @@ pub mod nova_insurance {
         )
     }
 
+    /// Withdraw own claim before any validator has voted
+    pub fn withdraw_claim(ctx: Context<WithdrawClaim>) -> Result<()> {
+        instructions::withdraw_claim(ctx)
+    }
+
+    /// Replace assigned validators who missed the voting deadline
+    pub fn replace_unresponsive_validators<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReplaceUnresponsiveValidators<'info>>,
+    ) -> Result<()> {
+        instructions::replace_unresponsive_validators(ctx)
+    }
+
+    /// Recuse from a conflicted claim and draw a replacement validator
+    pub fn recuse_from_claim(ctx: Context<RecuseFromClaim>) -> Result<()> {
+        instructions::recuse_from_claim(ctx)
+    }
+
+    /// Appeal a rejected claim to a larger validator panel
+    pub fn appeal_claim(ctx: Context<AppealClaim>) -> Result<()> {
+        instructions::appeal_claim(ctx)
+    }
+
+    /// Refund the appeal bond after a successful appeal
+    pub fn refund_appeal_bond(ctx: Context<RefundAppealBond>) -> Result<()> {
+        instructions::refund_appeal_bond(ctx)
+    }
+
+    /// Penalize an original majority voter overturned on appeal
+    pub fn penalize_overturned_validator(
+        ctx: Context<PenalizeOverturnedValidator>,
+    ) -> Result<()> {
+        instructions::penalize_overturned_validator(ctx)
+    }
+
     /// Stake SOL to become a validator
     pub fn stake_as_validator(
         ctx: Context<StakeAsValidator>,
@@ -77,13 +166,74 @@ pub mod nova_insurance {
         instructions::initialize_validator_registry(ctx)
     }
 
-    /// Validate a claim (approve or reject)
+    /// Initialize the validator rewards vault for a pool
+    pub fn initialize_validator_rewards(
+        ctx: Context<InitializeValidatorRewards>,
+    ) -> Result<()> {
+        instructions::initialize_validator_rewards(ctx)
+    }
+
+    /// Initialize the slashing treasury for a pool
+    pub fn initialize_slashing_treasury(
+        ctx: Context<InitializeSlashingTreasury>,
+    ) -> Result<()> {
+        instructions::initialize_slashing_treasury(ctx)
+    }
+
+    /// Declare wallets linked to a validator for conflict-of-interest recusal
+    pub fn declare_linked_wallets(
+        ctx: Context<DeclareLinkedWallets>,
+        wallets: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::declare_linked_wallets(ctx, wallets)
+    }
+
+    /// Claim USDC rewards earned from settled validations
+    pub fn claim_validator_rewards(ctx: Context<ClaimValidatorRewards>) -> Result<()> {
+        instructions::claim_validator_rewards(ctx)
+    }
+
+    /// Top up an existing validator stake
+    pub fn add_stake(ctx: Context<AddStake>, amount: u64) -> Result<()> {
+        instructions::add_stake(ctx, amount)
+    }
+
+    /// Leave a pool's validator set and start the unstake cooldown
+    pub fn request_unstake(ctx: Context<RequestUnstake>) -> Result<()> {
+        instructions::request_unstake(ctx)
+    }
+
+    /// Withdraw a validator's stake after the cooldown and close the stake account
+    pub fn withdraw_stake(ctx: Context<WithdrawStake>) -> Result<()> {
+        instructions::withdraw_stake(ctx)
+    }
+
+    /// Commit a hashed vote on a claim
+    pub fn commit_vote(ctx: Context<CommitVote>, commitment: [u8; 32]) -> Result<()> {
+        instructions::commit_vote(ctx, commitment)
+    }
+
+    /// Validate a claim (approve or reject) by revealing a committed vote
     pub fn validate_claim(
         ctx: Context<ValidateClaim>,
         approve: bool,
+        approved_amount: u64,
         reason: String,
+        salt: [u8; 32],
     ) -> Result<()> {
-        instructions::validate_claim(ctx, approve, reason)
+        instructions::validate_claim(ctx, approve, approved_amount, reason, salt)
+    }
+
+    /// Settle a validator's vote on a decided claim (reputation by closeness to outcome)
+    pub fn settle_validation(ctx: Context<SettleValidation>) -> Result<()> {
+        instructions::settle_validation(ctx)
+    }
+
+    /// Finalize a claim from revealed votes after the reveal deadline
+    pub fn finalize_claim_validation<'info>(
+        ctx: Context<'_, '_, '_, 'info, FinalizeClaimValidation<'info>>,
+    ) -> Result<()> {
+        instructions::finalize_claim_validation(ctx)
     }
 
     /// Initialize VRF state for a pool
@@ -92,11 +242,8 @@ pub mod nova_insurance {
     }
 
     /// Request validator selection using VRF
-    pub fn request_validator_selection(
-        ctx: Context<RequestValidatorSelection>,
-        claim_id: Pubkey,
-    ) -> Result<()> {
-        instructions::request_validator_selection(ctx, claim_id)
+    pub fn request_validator_selection(ctx: Context<RequestValidatorSelection>) -> Result<()> {
+        instructions::request_validator_selection(ctx)
     }
 
     /// Fulfill VRF callback for validator selection
@@ -143,4 +290,124 @@ pub mod nova_insurance {
     pub fn withdraw_from_yield(ctx: Context<WithdrawFromYield>, amount: u64) -> Result<()> {
         instructions::withdraw_from_yield(ctx, amount)
     }
+
+    /// Create the underwriter share mint for a pool
+    pub fn initialize_share_mint(
+        ctx: Context<InitializeShareMint>,
+        withdrawal_cooldown: i64,
+    ) -> Result<()> {
+        instructions::initialize_share_mint(ctx, withdrawal_cooldown)
+    }
+
+    /// Deposit underwriting capital in exchange for pool shares
+    pub fn deposit_capital(ctx: Context<DepositCapital>, amount: u64) -> Result<()> {
+        instructions::deposit_capital(ctx, amount)
+    }
+
+    /// Queue pool shares for withdrawal
+    pub fn request_capital_withdrawal(
+        ctx: Context<RequestCapitalWithdrawal>,
+        shares: u64,
+    ) -> Result<()> {
+        instructions::request_capital_withdrawal(ctx, shares)
+    }
+
+    /// Burn queued shares and withdraw their USDC value after the cooldown
+    pub fn withdraw_capital(ctx: Context<WithdrawCapital>) -> Result<()> {
+        instructions::withdraw_capital(ctx)
+    }
+
+    /// Pause or resume a pool
+    pub fn set_pool_status(ctx: Context<SetPoolStatus>, status: PoolStatus) -> Result<()> {
+        instructions::set_pool_status(ctx, status)
+    }
+
+    /// Start winding down a pool
+    pub fn begin_wind_down(ctx: Context<BeginWindDown>) -> Result<()> {
+        instructions::begin_wind_down(ctx)
+    }
+
+    /// Close a wound-down pool and snapshot its residual balance
+    pub fn finalize_wind_down(ctx: Context<FinalizeWindDown>) -> Result<()> {
+        instructions::finalize_wind_down(ctx)
+    }
+
+    /// Claim a member's pro-rata share of a closed pool's residual balance
+    pub fn claim_residual(ctx: Context<ClaimResidual>) -> Result<()> {
+        instructions::claim_residual(ctx)
+    }
+
+    /// Close a closed pool's vault once all members have been paid out
+    pub fn close_pool_vault(ctx: Context<ClosePoolVault>) -> Result<()> {
+        instructions::close_pool_vault(ctx)
+    }
+
+    /// Set or clear the pool's emergency guardian
+    pub fn set_guardian(ctx: Context<SetGuardian>, guardian: Option<Pubkey>) -> Result<()> {
+        instructions::set_guardian(ctx, guardian)
+    }
+
+    /// Pause instruction categories (guardian only)
+    pub fn pause_categories(ctx: Context<GuardianAction>, categories: u8) -> Result<()> {
+        instructions::pause_categories(ctx, categories)
+    }
+
+    /// Unpause instruction categories (guardian only)
+    pub fn unpause_categories(ctx: Context<GuardianAction>, categories: u8) -> Result<()> {
+        instructions::unpause_categories(ctx, categories)
+    }
+
+    /// Propose handing pool authority to a new key (e.g. a multisig signer)
+    pub fn propose_authority_transfer(
+        ctx: Context<ProposeAuthorityTransfer>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::propose_authority_transfer(ctx, new_authority)
+    }
+
+    /// Accept a pending pool authority transfer
+    pub fn accept_authority_transfer(ctx: Context<AcceptAuthorityTransfer>) -> Result<()> {
+        instructions::accept_authority_transfer(ctx)
+    }
+
+    /// Create an M-of-N multisig usable as a pool authority
+    pub fn create_multisig(
+        ctx: Context<CreateMultisig>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+        multisig_index: u8,
+    ) -> Result<()> {
+        instructions::create_multisig(ctx, signers, threshold, multisig_index)
+    }
+
+    /// Propose an instruction for the multisig to execute
+    pub fn propose_multisig_transaction(
+        ctx: Context<ProposeMultisigTransaction>,
+        program_id: Pubkey,
+        accounts: Vec<TransactionAccount>,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        instructions::propose_multisig_transaction(ctx, program_id, accounts, data)
+    }
+
+    /// Approve a pending multisig transaction
+    pub fn approve_multisig_transaction(ctx: Context<ApproveMultisigTransaction>) -> Result<()> {
+        instructions::approve_multisig_transaction(ctx)
+    }
+
+    /// Execute a multisig transaction that has reached its threshold
+    pub fn execute_multisig_transaction<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteMultisigTransaction<'info>>,
+    ) -> Result<()> {
+        instructions::execute_multisig_transaction(ctx)
+    }
+
+    /// Replace multisig signers and threshold (executed via the multisig)
+    pub fn set_multisig_config(
+        ctx: Context<SetMultisigConfig>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::set_multisig_config(ctx, signers, threshold)
+    }
 }
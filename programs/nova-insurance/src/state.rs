@@ -1,4 +1,10 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+use crate::errors::NovaError;
+
+/// Denominator for basis-point percentages
+pub const BPS_DENOMINATOR: u16 = 10_000;
 
 /// Pool types for different insurance categories
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
@@ -13,6 +19,69 @@ impl Space for PoolType {
     const INIT_SPACE: usize = 1; // enum discriminant
 }
 
+impl PoolType {
+    /// Premium multiplier for the pool's category (basis points)
+    pub fn pricing_multiplier_bps(&self) -> u16 {
+        match self {
+            PoolType::Medical => 10_000,
+            PoolType::Weather => 12_000,
+            PoolType::Crop => 15_000,
+            PoolType::General => 10_000,
+        }
+    }
+}
+
+/// Pool lifecycle status
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PoolStatus {
+    /// Accepting members, premiums and claims
+    Active,
+    /// Temporarily closed to new members, claims and yield deposits
+    Paused,
+    /// Settling outstanding claims before closure
+    WindingDown,
+    /// Residual funds are being returned to members
+    Closed,
+}
+
+impl Space for PoolStatus {
+    const INIT_SPACE: usize = 1; // enum discriminant
+}
+
+/// Instruction categories the pool guardian can pause independently (bit flags)
+pub struct PauseCategory;
+
+impl PauseCategory {
+    /// join_pool, pay_premium, update_coverage, leave_pool, capital deposits and withdrawals
+    pub const MEMBERSHIP: u8 = 1 << 0;
+    /// submit_claim
+    pub const CLAIMS: u8 = 1 << 1;
+    /// Validator selection and validate_claim
+    pub const VALIDATION: u8 = 1 << 2;
+    /// Distribution queue, payouts and residual distribution
+    pub const DISTRIBUTION: u8 = 1 << 3;
+    /// deposit_to_yield and withdraw_from_yield
+    pub const YIELD: u8 = 1 << 4;
+    /// Every category
+    pub const ALL: u8 = Self::MEMBERSHIP
+        | Self::CLAIMS
+        | Self::VALIDATION
+        | Self::DISTRIBUTION
+        | Self::YIELD;
+}
+
+/// Member risk tier used to scale premiums
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RiskTier {
+    Low,
+    Standard,
+    High,
+}
+
+impl Space for RiskTier {
+    const INIT_SPACE: usize = 1; // enum discriminant
+}
+
 /// Incident types for claims
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
 pub enum IncidentType {
@@ -37,6 +106,7 @@ pub enum ClaimStatus {
     Rejected,
     Distributed,
     Queued,
+    Withdrawn,
 }
 
 impl Space for ClaimStatus {
@@ -48,12 +118,244 @@ impl Space for ClaimStatus {
 pub struct Validation {
     pub validator: Pubkey,
     pub approved: bool,
+    pub approved_amount: u64,
     pub reason: String,
     pub timestamp: i64,
+    pub settled: bool,
 }
 
 impl Space for Validation {
-    const INIT_SPACE: usize = 32 + 1 + 4 + 200 + 8; // validator + approved + string len + reason (max 200) + timestamp
+    const INIT_SPACE: usize = 32 + 1 + 8 + 4 + 200 + 8 + 1; // validator + approved + approved_amount + string len + reason (max 200) + timestamp + settled
+}
+
+/// Hashed vote submitted by an assigned validator during the commit phase
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct VoteCommitment {
+    pub validator: Pubkey,
+    pub commitment: [u8; 32],
+    pub revealed: bool,
+}
+
+impl Space for VoteCommitment {
+    const INIT_SPACE: usize = 32 + 32 + 1; // validator + commitment + revealed
+}
+
+/// Registered validator with the stake and reputation used to weight selection
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct RegisteredValidator {
+    pub validator: Pubkey,
+    pub stake_amount: u64,
+    pub reputation_score: u32,
+}
+
+impl Space for RegisteredValidator {
+    const INIT_SPACE: usize = 32 + 8 + 4; // validator + stake_amount + reputation_score
+}
+
+impl RegisteredValidator {
+    /// Selection weight: stake times reputation
+    pub fn weight(&self) -> u128 {
+        self.stake_amount as u128 * self.reputation_score as u128
+    }
+}
+
+/// Number of open claims a registered validator is currently assigned to
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ValidatorAssignment {
+    pub validator: Pubkey,
+    pub open_claims: u16,
+}
+
+impl Space for ValidatorAssignment {
+    const INIT_SPACE: usize = 32 + 2; // validator + open_claims
+}
+
+/// Pool terms that can be changed after creation through the timelocked
+/// propose/execute config flow
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PoolConfigParams {
+    /// Premium per billing period in USDC (lamports)
+    pub premium_amount: u64,
+    
+    /// Maximum coverage amount per user in USDC (lamports)
+    pub coverage_amount: u64,
+    
+    /// Minimum number of validators required for claim verification
+    pub min_validators: u8,
+    
+    /// Time window for claims (in seconds)
+    pub claim_period: i64,
+    
+    /// Timelock for future config changes (in seconds)
+    pub config_update_delay: i64,
+    
+    /// Length of one premium billing period (in seconds)
+    pub billing_period: i64,
+    
+    /// Time after paid-through before overdue coverage can be lapsed (in seconds)
+    pub grace_period: i64,
+    
+    /// Discount on multi-period prepayments (basis points)
+    pub prepay_discount_bps: u16,
+    
+    /// Maximum number of billing periods payable in advance
+    pub max_prepay_periods: u8,
+    
+    /// Premium per billing period as a share of coverage (basis points, 0 = flat premium)
+    pub premium_rate_bps: u16,
+    
+    /// Whether premiums are scaled by the pool type's multiplier
+    pub scale_by_pool_type: bool,
+    
+    /// Premium multipliers for Low, Standard and High risk tiers (basis points)
+    pub risk_tier_multipliers_bps: [u16; 3],
+    
+    /// Time after coverage starts before incidents are covered (in seconds)
+    pub waiting_period: i64,
+    
+    /// Per-IncidentType waiting period overrides (in seconds, 0 = use waiting_period)
+    pub incident_waiting_periods: [i64; 6],
+    
+    /// Fixed amount deducted from each approved claim in USDC (lamports)
+    pub deductible: u64,
+    
+    /// Member's share of each claim after the deductible (basis points)
+    pub coinsurance_bps: u16,
+    
+    /// Cap on the payout of a single claim in USDC (lamports, 0 = no cap)
+    pub max_payout_per_claim: u64,
+    
+    /// Maximum claimed and paid per member per policy year in USDC (lamports, 0 = member's coverage amount)
+    pub annual_aggregate_limit: u64,
+    
+    /// Maximum claims per member per policy year (0 = unlimited)
+    pub max_claims_per_year: u8,
+    
+    /// Time after rejection during which the claimant may appeal (in seconds)
+    pub appeal_window: i64,
+    
+    /// Bond posted to appeal a rejected claim in USDC (lamports)
+    pub appeal_bond: u64,
+    
+    /// Number of validators on an appeal panel (must exceed min_validators, max 10)
+    pub appeal_panel_size: u8,
+    
+    /// Time assigned validators have to vote before they can be replaced (in seconds)
+    pub voting_period: i64,
+    
+    /// Share of slashed stake paid to the validators who voted with the majority (basis points)
+    pub slash_redistribution_bps: u16,
+    
+    /// Seconds a validator must wait between requesting to unstake and withdrawing
+    pub unstake_cooldown: i64,
+    
+    /// Minimum stake (lamports) a validator needs to be selectable for claims
+    pub min_validator_stake: u64,
+    
+    /// Share of each premium payment set aside for validator rewards (basis points)
+    pub validator_reward_bps: u16,
+    
+    /// USDC reward for a validation matching the outcome at full reputation
+    pub validation_reward: u64,
+    
+    /// Minimum reputation score a validator needs to be selectable for claims
+    pub min_validator_reputation: u32,
+    
+    /// Whether validators who are members of the pool are barred from judging its claims
+    pub bar_member_validators: bool,
+}
+
+impl Space for PoolConfigParams {
+    const INIT_SPACE: usize = 8 + // premium_amount
+        8 + // coverage_amount
+        1 + // min_validators
+        8 + // claim_period
+        8 + // config_update_delay
+        8 + // billing_period
+        8 + // grace_period
+        2 + // prepay_discount_bps
+        1 + // max_prepay_periods
+        2 + // premium_rate_bps
+        1 + // scale_by_pool_type
+        2 * 3 + // risk_tier_multipliers_bps
+        8 + // waiting_period
+        8 * 6 + // incident_waiting_periods
+        8 + // deductible
+        2 + // coinsurance_bps
+        8 + // max_payout_per_claim
+        8 + // annual_aggregate_limit
+        1 + // max_claims_per_year
+        8 + // appeal_window
+        8 + // appeal_bond
+        1 + // appeal_panel_size
+        8 + // voting_period
+        2 + // slash_redistribution_bps
+        8 + // unstake_cooldown
+        8 + // min_validator_stake
+        2 + // validator_reward_bps
+        8 + // validation_reward
+        4 + // min_validator_reputation
+        1; // bar_member_validators
+}
+
+impl PoolConfigParams {
+    /// Validate proposed terms with the same rules as initialize_pool
+    pub fn validate(&self) -> Result<()> {
+        require!(self.premium_amount > 0, NovaError::InvalidPremiumAmount);
+        require!(
+            self.coverage_amount > self.premium_amount,
+            NovaError::InvalidCoverageAmount
+        );
+        require!(self.min_validators >= 3, NovaError::InsufficientValidators);
+        require!(self.claim_period > 0, NovaError::InvalidClaimPeriod);
+        require!(self.config_update_delay >= 0, NovaError::InvalidTimestamp);
+        require!(self.billing_period > 0, NovaError::InvalidBillingPeriod);
+        require!(self.grace_period >= 0, NovaError::InvalidBillingPeriod);
+        require!(
+            self.prepay_discount_bps < BPS_DENOMINATOR,
+            NovaError::InvalidPrepayDiscount
+        );
+        require!(self.max_prepay_periods >= 1, NovaError::InvalidPremiumPeriods);
+        require!(
+            self.risk_tier_multipliers_bps.iter().all(|m| *m > 0),
+            NovaError::InvalidPremiumAmount
+        );
+        require!(self.waiting_period >= 0, NovaError::InvalidWaitingPeriod);
+        require!(
+            self.incident_waiting_periods.iter().all(|w| *w >= 0),
+            NovaError::InvalidWaitingPeriod
+        );
+        require!(
+            self.deductible < self.coverage_amount,
+            NovaError::InvalidCostSharing
+        );
+        require!(
+            self.coinsurance_bps < BPS_DENOMINATOR,
+            NovaError::InvalidCostSharing
+        );
+        require!(self.appeal_window >= 0, NovaError::InvalidAppealTerms);
+        require!(
+            self.appeal_panel_size > self.min_validators
+                && self.appeal_panel_size as usize <= ClaimRequest::MAX_VALIDATORS,
+            NovaError::InvalidAppealTerms
+        );
+        require!(self.voting_period > 0, NovaError::InvalidVotingPeriod);
+        require!(
+            self.slash_redistribution_bps <= BPS_DENOMINATOR,
+            NovaError::InvalidSlashingTerms
+        );
+        require!(self.unstake_cooldown > 0, NovaError::InvalidUnstakeCooldown);
+        require!(self.min_validator_stake > 0, NovaError::InsufficientStake);
+        require!(
+            self.validator_reward_bps < BPS_DENOMINATOR,
+            NovaError::InvalidValidatorRewardTerms
+        );
+        require!(
+            self.min_validator_reputation <= ValidatorStake::MAX_REPUTATION,
+            NovaError::InvalidMinReputation
+        );
+        Ok(())
+    }
 }
 
 /// Main insurance pool account
@@ -70,6 +372,15 @@ pub struct InsurancePool {
     /// Authority that can manage this pool
     pub authority: Pubkey,
     
+    /// Wallet that created the pool (PDA seed, never changes)
+    pub creator: Pubkey,
+    
+    /// Creator-scoped index distinguishing this pool from the creator's other pools (PDA seed)
+    pub pool_index: u8,
+    
+    /// Page of the pool directory this pool is listed in
+    pub directory_page: u32,
+    
     /// USDC vault address where premiums are stored
     pub vault: Pubkey,
     
@@ -106,6 +417,140 @@ pub struct InsurancePool {
     /// Timestamp of last yield update
     pub last_yield_update: i64,
     
+    /// SPL mint for underwriter share tokens (set once capital deposits are enabled)
+    pub share_mint: Option<Pubkey>,
+    
+    /// Delay between requesting and executing a capital withdrawal (in seconds)
+    pub withdrawal_cooldown: i64,
+    
+    /// Non-redeemable shares representing pool funds that existed before the
+    /// first underwriter deposit, so those funds cannot be claimed by underwriters
+    pub reserved_shares: u64,
+    
+    /// Number of claims currently in UnderValidation
+    pub claims_under_validation: u32,
+    
+    /// Approved claim payouts that have not been paid out yet
+    pub approved_unpaid_claims: u64,
+    
+    /// Minimum delay between proposing and executing a config change (in seconds)
+    pub config_update_delay: i64,
+    
+    /// Length of one premium billing period (in seconds)
+    pub billing_period: i64,
+    
+    /// Time after paid-through before overdue coverage can be lapsed (in seconds)
+    pub grace_period: i64,
+    
+    /// Discount on multi-period prepayments (basis points)
+    pub prepay_discount_bps: u16,
+    
+    /// Maximum number of billing periods payable in advance
+    pub max_prepay_periods: u8,
+    
+    /// Premium per billing period as a share of coverage (basis points, 0 = flat premium)
+    pub premium_rate_bps: u16,
+    
+    /// Whether premiums are scaled by the pool type's multiplier
+    pub scale_by_pool_type: bool,
+    
+    /// Premium multipliers for Low, Standard and High risk tiers (basis points)
+    pub risk_tier_multipliers_bps: [u16; 3],
+    
+    /// Time after coverage starts before incidents are covered (in seconds)
+    pub waiting_period: i64,
+    
+    /// Per-IncidentType waiting period overrides (in seconds, 0 = use waiting_period)
+    pub incident_waiting_periods: [i64; 6],
+    
+    /// Fixed amount deducted from each approved claim in USDC (lamports)
+    pub deductible: u64,
+    
+    /// Member's share of each claim after the deductible (basis points)
+    pub coinsurance_bps: u16,
+    
+    /// Cap on the payout of a single claim in USDC (lamports, 0 = no cap)
+    pub max_payout_per_claim: u64,
+    
+    /// Maximum claimed and paid per member per policy year in USDC (lamports, 0 = member's coverage amount)
+    pub annual_aggregate_limit: u64,
+    
+    /// Maximum claims per member per policy year (0 = unlimited)
+    pub max_claims_per_year: u8,
+    
+    /// Time after rejection during which the claimant may appeal (in seconds)
+    pub appeal_window: i64,
+    
+    /// Bond posted to appeal a rejected claim in USDC (lamports)
+    pub appeal_bond: u64,
+    
+    /// Number of validators on an appeal panel (must exceed min_validators, max 10)
+    pub appeal_panel_size: u8,
+    
+    /// Time assigned validators have to vote before they can be replaced (in seconds)
+    pub voting_period: i64,
+    
+    /// Share of slashed stake paid to the validators who voted with the majority (basis points)
+    pub slash_redistribution_bps: u16,
+    
+    /// Seconds a validator must wait between requesting to unstake and withdrawing
+    pub unstake_cooldown: i64,
+    
+    /// Minimum stake (lamports) a validator needs to be selectable for claims
+    pub min_validator_stake: u64,
+    
+    /// Share of each premium payment set aside for validator rewards (basis points)
+    pub validator_reward_bps: u16,
+    
+    /// USDC reward for a validation matching the outcome at full reputation
+    pub validation_reward: u64,
+    
+    /// Minimum reputation score a validator needs to be selectable for claims
+    pub min_validator_reputation: u32,
+    
+    /// Whether validators who are members of the pool are barred from judging its claims
+    pub bar_member_validators: bool,
+    
+    /// Lifecycle status of the pool
+    pub status: PoolStatus,
+    
+    /// Number of coverage accounts (active or lapsed) still open
+    pub member_accounts: u32,
+    
+    /// Sum of premiums_paid across open coverage accounts
+    pub total_member_premiums: u64,
+    
+    /// Vault balance snapshotted at closure for residual distribution
+    pub residual_balance: u64,
+    
+    /// Member premiums snapshotted at closure for residual distribution
+    pub residual_premiums: u64,
+    
+    /// Residual claims close at this time; any balance left afterwards can be swept
+    pub residual_claim_deadline: i64,
+    
+    /// Appeal bonds held in the vault that are not pool funds (pending or owed back)
+    pub appeal_bonds_held: u64,
+    
+    /// When the most recent appealable rejection was decided; the pool cannot
+    /// close until its appeal window has passed
+    pub last_rejection_at: i64,
+    
+    /// Emergency guardian that can pause instruction categories (separate from authority)
+    pub guardian: Option<Pubkey>,
+    
+    /// Currently paused instruction categories (PauseCategory bit flags)
+    pub paused_categories: u8,
+    
+    /// Proposed new authority awaiting acceptance
+    pub pending_authority: Option<Pubkey>,
+    
+    /// Number of claims ever submitted to the pool (next claim sequence number)
+    pub claim_sequence: u64,
+    
+    /// Whether the pool's VRF state has been initialized
+    pub vrf_initialized: bool,
+    
     /// Timestamp when pool was created
     pub created_at: i64,
     
@@ -119,6 +564,9 @@ impl InsurancePool {
         32 + // pool_id
         1 + // pool_type (enum)
         32 + // authority
+        32 + // creator
+        1 + // pool_index
+        4 + // directory_page
         32 + // vault
         1 + 32 + // yield_vault (option + pubkey)
         8 + // premium_amount
@@ -131,53 +579,726 @@ impl InsurancePool {
         8 + // yield_deposited
         8 + // yield_earned
         8 + // last_yield_update
+        1 + 32 + // share_mint (option + pubkey)
+        8 + // withdrawal_cooldown
+        8 + // reserved_shares
+        4 + // claims_under_validation
+        8 + // approved_unpaid_claims
+        8 + // config_update_delay
+        8 + // billing_period
+        8 + // grace_period
+        2 + // prepay_discount_bps
+        1 + // max_prepay_periods
+        2 + // premium_rate_bps
+        1 + // scale_by_pool_type
+        2 * 3 + // risk_tier_multipliers_bps
+        8 + // waiting_period
+        8 * 6 + // incident_waiting_periods
+        8 + // deductible
+        2 + // coinsurance_bps
+        8 + // max_payout_per_claim
+        8 + // annual_aggregate_limit
+        1 + // max_claims_per_year
+        8 + // appeal_window
+        8 + // appeal_bond
+        1 + // appeal_panel_size
+        8 + // voting_period
+        2 + // slash_redistribution_bps
+        8 + // unstake_cooldown
+        8 + // min_validator_stake
+        2 + // validator_reward_bps
+        8 + // validation_reward
+        4 + // min_validator_reputation
+        1 + // bar_member_validators
+        1 + // status
+        4 + // member_accounts
+        8 + // total_member_premiums
+        8 + // residual_balance
+        8 + // residual_premiums
+        8 + // residual_claim_deadline
+        8 + // appeal_bonds_held
+        8 + // last_rejection_at
+        1 + 32 + // guardian (option + pubkey)
+        1 + // paused_categories
+        1 + 32 + // pending_authority (option + pubkey)
+        8 + // claim_sequence
+        1 + // vrf_initialized
+        8 + // created_at
+        1; // bump
+
+    /// Default timelock for pool config changes (7 days)
+    pub const DEFAULT_CONFIG_UPDATE_DELAY: i64 = 7 * 24 * 60 * 60;
+
+    /// Default premium billing period (30 days)
+    pub const DEFAULT_BILLING_PERIOD: i64 = 30 * 24 * 60 * 60;
+
+    /// Default grace period before overdue coverage lapses (7 days)
+    pub const DEFAULT_GRACE_PERIOD: i64 = 7 * 24 * 60 * 60;
+
+    /// Default cap on prepaid billing periods (one year of monthly periods)
+    pub const DEFAULT_MAX_PREPAY_PERIODS: u8 = 12;
+
+    /// Default risk tier multipliers (no tier scaling)
+    pub const DEFAULT_RISK_TIER_MULTIPLIERS_BPS: [u16; 3] = [BPS_DENOMINATOR; 3];
+
+    /// Default waiting period before new coverage can claim (14 days)
+    pub const DEFAULT_WAITING_PERIOD: i64 = 14 * 24 * 60 * 60;
+
+    /// Default window for appealing a rejected claim (7 days)
+    pub const DEFAULT_APPEAL_WINDOW: i64 = 7 * 24 * 60 * 60;
+
+    /// Default time assigned validators have to vote (3 days)
+    pub const DEFAULT_VOTING_PERIOD: i64 = 3 * 24 * 60 * 60;
+
+    /// Default share of slashed stake redistributed to majority voters (50%)
+    pub const DEFAULT_SLASH_REDISTRIBUTION_BPS: u16 = 5_000;
+
+    /// Default validator unstaking cooldown (7 days)
+    pub const DEFAULT_UNSTAKE_COOLDOWN: i64 = 7 * 24 * 60 * 60;
+
+    /// Default minimum validator stake (0.1 SOL)
+    pub const DEFAULT_MIN_VALIDATOR_STAKE: u64 = 100_000_000;
+
+    /// Default minimum validator reputation for selection (10%)
+    pub const DEFAULT_MIN_VALIDATOR_REPUTATION: u32 = 1_000;
+
+    /// Time members have to claim their residual share after closure (180 days)
+    pub const RESIDUAL_CLAIM_WINDOW: i64 = 180 * 24 * 60 * 60;
+
+    /// Timelock for config changes; pools without members or funds have no
+    /// one to protect, so changes there apply immediately
+    pub fn config_delay(&self) -> i64 {
+        if self.member_accounts == 0 && self.total_pooled == 0 {
+            0
+        } else {
+            self.config_update_delay
+        }
+    }
+
+    /// Current configurable terms of the pool
+    pub fn config(&self) -> PoolConfigParams {
+        PoolConfigParams {
+            premium_amount: self.premium_amount,
+            coverage_amount: self.coverage_amount,
+            min_validators: self.min_validators,
+            claim_period: self.claim_period,
+            config_update_delay: self.config_update_delay,
+            billing_period: self.billing_period,
+            grace_period: self.grace_period,
+            prepay_discount_bps: self.prepay_discount_bps,
+            max_prepay_periods: self.max_prepay_periods,
+            premium_rate_bps: self.premium_rate_bps,
+            scale_by_pool_type: self.scale_by_pool_type,
+            risk_tier_multipliers_bps: self.risk_tier_multipliers_bps,
+            waiting_period: self.waiting_period,
+            incident_waiting_periods: self.incident_waiting_periods,
+            deductible: self.deductible,
+            coinsurance_bps: self.coinsurance_bps,
+            max_payout_per_claim: self.max_payout_per_claim,
+            annual_aggregate_limit: self.annual_aggregate_limit,
+            max_claims_per_year: self.max_claims_per_year,
+            appeal_window: self.appeal_window,
+            appeal_bond: self.appeal_bond,
+            appeal_panel_size: self.appeal_panel_size,
+            voting_period: self.voting_period,
+            slash_redistribution_bps: self.slash_redistribution_bps,
+            unstake_cooldown: self.unstake_cooldown,
+            min_validator_stake: self.min_validator_stake,
+            validator_reward_bps: self.validator_reward_bps,
+            validation_reward: self.validation_reward,
+            min_validator_reputation: self.min_validator_reputation,
+            bar_member_validators: self.bar_member_validators,
+        }
+    }
+
+    /// Apply new configurable terms to the pool
+    pub fn apply_config(&mut self, params: &PoolConfigParams) {
+        self.premium_amount = params.premium_amount;
+        self.coverage_amount = params.coverage_amount;
+        self.min_validators = params.min_validators;
+        self.claim_period = params.claim_period;
+        self.config_update_delay = params.config_update_delay;
+        self.billing_period = params.billing_period;
+        self.grace_period = params.grace_period;
+        self.prepay_discount_bps = params.prepay_discount_bps;
+        self.max_prepay_periods = params.max_prepay_periods;
+        self.premium_rate_bps = params.premium_rate_bps;
+        self.scale_by_pool_type = params.scale_by_pool_type;
+        self.risk_tier_multipliers_bps = params.risk_tier_multipliers_bps;
+        self.waiting_period = params.waiting_period;
+        self.incident_waiting_periods = params.incident_waiting_periods;
+        self.deductible = params.deductible;
+        self.coinsurance_bps = params.coinsurance_bps;
+        self.max_payout_per_claim = params.max_payout_per_claim;
+        self.annual_aggregate_limit = params.annual_aggregate_limit;
+        self.max_claims_per_year = params.max_claims_per_year;
+        self.appeal_window = params.appeal_window;
+        self.appeal_bond = params.appeal_bond;
+        self.appeal_panel_size = params.appeal_panel_size;
+        self.voting_period = params.voting_period;
+        self.slash_redistribution_bps = params.slash_redistribution_bps;
+        self.unstake_cooldown = params.unstake_cooldown;
+        self.min_validator_stake = params.min_validator_stake;
+        self.validator_reward_bps = params.validator_reward_bps;
+        self.validation_reward = params.validation_reward;
+        self.min_validator_reputation = params.min_validator_reputation;
+        self.bar_member_validators = params.bar_member_validators;
+    }
+
+    /// Quote the premium per billing period for a coverage level and risk tier
+    ///
+    /// The base premium is `coverage * premium_rate_bps`, floored at the pool's
+    /// `premium_amount` (or just `premium_amount` when no rate is set), then
+    /// scaled by the pool type multiplier (if enabled) and the member's tier.
+    pub fn quote_premium(&self, coverage_amount: u64, risk_tier: RiskTier) -> Result<u64> {
+        let mut premium = self.premium_amount as u128;
+
+        if self.premium_rate_bps > 0 {
+            let proportional = (coverage_amount as u128)
+                .checked_mul(self.premium_rate_bps as u128)
+                .ok_or(NovaError::MathOverflow)?
+                .checked_div(BPS_DENOMINATOR as u128)
+                .ok_or(NovaError::MathOverflow)?;
+            premium = premium.max(proportional);
+        }
+
+        if self.scale_by_pool_type {
+            premium = premium
+                .checked_mul(self.pool_type.pricing_multiplier_bps() as u128)
+                .ok_or(NovaError::MathOverflow)?
+                .checked_div(BPS_DENOMINATOR as u128)
+                .ok_or(NovaError::MathOverflow)?;
+        }
+
+        premium = premium
+            .checked_mul(self.risk_tier_multipliers_bps[risk_tier as usize] as u128)
+            .ok_or(NovaError::MathOverflow)?
+            .checked_div(BPS_DENOMINATOR as u128)
+            .ok_or(NovaError::MathOverflow)?;
+
+        Ok(u64::try_from(premium).map_err(|_| NovaError::MathOverflow)?)
+    }
+
+    /// Portion of a premium payment routed to the validator rewards vault
+    pub fn validator_reward_share(&self, premium: u64) -> Result<u64> {
+        Ok((premium as u128)
+            .checked_mul(self.validator_reward_bps as u128)
+            .ok_or(NovaError::MathOverflow)?
+            .checked_div(BPS_DENOMINATOR as u128)
+            .ok_or(NovaError::MathOverflow)? as u64)
+    }
+
+    /// Premium due for `periods` billing periods at `premium_per_period`, with the
+    /// prepay discount applied to multi-period payments. Returns (amount due, discount given).
+    pub fn premium_for_periods(&self, premium_per_period: u64, periods: u8) -> Result<(u64, u64)> {
+        let gross = premium_per_period
+            .checked_mul(periods as u64)
+            .ok_or(NovaError::MathOverflow)?;
+        if periods <= 1 {
+            return Ok((gross, 0));
+        }
+
+        let discount = (gross as u128)
+            .checked_mul(self.prepay_discount_bps as u128)
+            .ok_or(NovaError::MathOverflow)?
+            .checked_div(BPS_DENOMINATOR as u128)
+            .ok_or(NovaError::MathOverflow)? as u64;
+
+        Ok((gross.saturating_sub(discount), discount))
+    }
+
+    /// Waiting period applying to an incident type
+    pub fn waiting_period_for(&self, incident_type: IncidentType) -> i64 {
+        match self.incident_waiting_periods[incident_type as usize] {
+            0 => self.waiting_period,
+            override_period => override_period,
+        }
+    }
+
+    /// Longest waiting period applying to any incident type
+    pub fn max_waiting_period(&self) -> i64 {
+        self.incident_waiting_periods
+            .iter()
+            .copied()
+            .fold(self.waiting_period, i64::max)
+    }
+
+    /// Aggregate amount a member with `coverage_amount` may claim per policy year
+    pub fn annual_limit_for(&self, coverage_amount: u64) -> u64 {
+        if self.annual_aggregate_limit == 0 {
+            coverage_amount
+        } else {
+            self.annual_aggregate_limit
+        }
+    }
+
+    /// Apply the pool's cost-sharing terms to a claim amount:
+    /// the deductible first, then coinsurance on the remainder, then the per-claim cap.
+    pub fn calculate_payout(&self, amount_requested: u64) -> Result<ClaimPayout> {
+        let after_deductible = amount_requested.saturating_sub(self.deductible);
+        let deductible_applied = amount_requested - after_deductible;
+
+        let coinsurance_applied = (after_deductible as u128)
+            .checked_mul(self.coinsurance_bps as u128)
+            .ok_or(NovaError::MathOverflow)?
+            .checked_div(BPS_DENOMINATOR as u128)
+            .ok_or(NovaError::MathOverflow)? as u64;
+
+        let mut payout_amount = after_deductible - coinsurance_applied;
+        if self.max_payout_per_claim > 0 {
+            payout_amount = payout_amount.min(self.max_payout_per_claim);
+        }
+
+        Ok(ClaimPayout {
+            payout_amount,
+            deductible_applied,
+            coinsurance_applied,
+        })
+    }
+
+    /// Signer seeds for the pool PDA, which owns the vault and share mint
+    pub fn signer_seeds(&self) -> [&[u8]; 4] {
+        [
+            b"pool".as_ref(),
+            self.creator.as_ref(),
+            std::slice::from_ref(&self.pool_index),
+            std::slice::from_ref(&self.bump),
+        ]
+    }
+
+    /// Require the pool to be Active
+    pub fn require_active(&self) -> Result<()> {
+        require!(self.status == PoolStatus::Active, NovaError::PoolNotActive);
+        Ok(())
+    }
+
+    /// Require the guardian has not paused the given instruction category
+    pub fn require_not_paused(&self, category: u8) -> Result<()> {
+        require!(
+            self.paused_categories & category == 0,
+            NovaError::CategoryPaused
+        );
+        Ok(())
+    }
+
+    /// Shares backed by NAV: outstanding share tokens plus reserved shares
+    pub fn effective_share_supply(&self, share_supply: u64) -> Result<u64> {
+        Ok(share_supply
+            .checked_add(self.reserved_shares)
+            .ok_or(NovaError::MathOverflow)?)
+    }
+
+    /// Net asset value backing underwriter shares: vault balance + funds deposited
+    /// to yield - approved but unpaid claims - appeal bonds owed to claimants
+    pub fn net_asset_value(&self, vault_balance: u64) -> Result<u64> {
+        let gross = vault_balance
+            .checked_add(self.yield_deposited)
+            .ok_or(NovaError::MathOverflow)?;
+        Ok(gross
+            .saturating_sub(self.approved_unpaid_claims)
+            .saturating_sub(self.appeal_bonds_held))
+    }
+}
+
+/// Breakdown of an approved claim's payout after cost sharing
+#[derive(Clone, Copy, Debug)]
+pub struct ClaimPayout {
+    pub payout_amount: u64,
+    pub deductible_applied: u64,
+    pub coinsurance_applied: u64,
+}
+
+/// One page of the directory of all pools of a given type, so clients can
+/// enumerate pools without scanning program accounts. Pages are numbered from
+/// 0 and a new page can be opened once the previous one is full.
+#[account]
+#[derive(InitSpace)]
+pub struct PoolDirectory {
+    /// Pool type listed in this directory
+    pub pool_type: PoolType,
+    
+    /// Page number within the pool type's directory (PDA seed)
+    pub page: u32,
+    
+    /// Pools of this type listed on this page (max 100)
+    #[max_len(100)]
+    pub pools: Vec<Pubkey>,
+    
+    /// Total number of pools listed on this page
+    pub total_pools: u32,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PoolDirectory {
+    /// Calculate space needed for PoolDirectory account
+    pub const LEN: usize = 8 + // discriminator
+        1 + // pool_type
+        4 + // page
+        4 + (32 * 100) + // pools (vec + max 100 pubkeys)
+        4 + // total_pools
+        1; // bump
+
+    /// Maximum number of pools listed per directory page
+    pub const MAX_POOLS: usize = 100;
+
+    /// Whether the page has room for another pool
+    pub fn is_full(&self) -> bool {
+        self.pools.len() >= Self::MAX_POOLS
+    }
+}
+
+/// Pool parameter change queued by the authority, applied after the pool's timelock
+#[account]
+#[derive(InitSpace)]
+pub struct PendingPoolConfig {
+    /// Pool this change applies to
+    pub pool: Pubkey,
+    
+    /// Proposed pool terms
+    pub params: PoolConfigParams,
+    
+    /// Timestamp when the change was proposed
+    pub proposed_at: i64,
+    
+    /// Earliest timestamp the change can be executed
+    pub executable_at: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl PendingPoolConfig {
+    /// Calculate space needed for PendingPoolConfig account
+    pub const LEN: usize = 8 + // discriminator
+        32 + // pool
+        PoolConfigParams::INIT_SPACE + // params
+        8 + // proposed_at
+        8 + // executable_at
+        1; // bump
+}
+
+/// User coverage account tracking individual member's insurance status
+#[account]
+#[derive(InitSpace)]
+pub struct UserCoverage {
+    /// User's wallet address
+    pub user: Pubkey,
+    
+    /// Insurance pool this coverage belongs to
+    pub pool: Pubkey,
+    
+    /// Total premiums paid by this user
+    pub premiums_paid: u64,
+    
+    /// Timestamp of last premium payment
+    pub last_payment: i64,
+    
+    /// Whether coverage is currently active
+    pub coverage_active: bool,
+    
+    /// Amount of coverage this user has
+    pub coverage_amount: u64,
+    
+    /// Risk tier used to price this member's premium
+    pub risk_tier: RiskTier,
+    
+    /// Number of claims made by this user in the current policy year
+    pub claims_made: u8,
+    
+    /// Timestamp when user joined the pool
+    pub joined_at: i64,
+    
+    /// Start of the member's current continuous coverage period
+    pub covered_from: i64,
+    
+    /// Coverage amount already past its waiting period at the last increase
+    pub prior_coverage_amount: u64,
+    
+    /// Timestamp of the last coverage increase (its waiting period runs from here)
+    pub coverage_increased_at: i64,
+    
+    /// Timestamp through which premiums have been paid
+    pub paid_through: i64,
+    
+    /// Premium per billing period kept by the pool vault at the last payment
+    /// (after discounts and the validator reward share); basis for refunds
+    pub period_premium: u64,
+    
+    /// Number of this member's claims not yet rejected or paid out
+    pub open_claims: u8,
+    
+    /// Start of the member's current policy year
+    pub policy_year_start: i64,
+    
+    /// Amount counted toward the annual aggregate in the current policy year:
+    /// the requested amount of undecided claims, the payout of approved ones
+    pub claimed_this_year: u64,
+    
+    /// Amount paid out in the current policy year
+    pub paid_this_year: u64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl UserCoverage {
+    /// Calculate space needed for UserCoverage account
+    pub const LEN: usize = 8 + // discriminator
+        32 + // user
+        32 + // pool
+        8 + // premiums_paid
+        8 + // last_payment
+        1 + // coverage_active
+        8 + // coverage_amount
+        1 + // risk_tier
+        1 + // claims_made
+        8 + // joined_at
+        8 + // covered_from
+        8 + // prior_coverage_amount
+        8 + // coverage_increased_at
+        8 + // paid_through
+        8 + // period_premium
+        1 + // open_claims
+        8 + // policy_year_start
+        8 + // claimed_this_year
+        8 + // paid_this_year
+        1; // bump
+
+    /// Length of a policy year for aggregate limits (365 days)
+    pub const POLICY_YEAR: i64 = 365 * 24 * 60 * 60;
+
+    /// Start a new policy year, resetting annual totals, once the current one has ended
+    pub fn roll_policy_year(&mut self, now: i64) {
+        let elapsed = now.saturating_sub(self.policy_year_start);
+        if elapsed < Self::POLICY_YEAR {
+            return;
+        }
+
+        let years = elapsed / Self::POLICY_YEAR;
+        self.policy_year_start = self
+            .policy_year_start
+            .saturating_add(years.saturating_mul(Self::POLICY_YEAR));
+        self.claims_made = 0;
+        self.claimed_this_year = 0;
+        self.paid_this_year = 0;
+    }
+
+    /// Whether an incident at `timestamp` falls inside a paid coverage period
+    pub fn covers_incident(&self, timestamp: i64) -> bool {
+        timestamp >= self.covered_from && timestamp <= self.paid_through
+    }
+
+    /// Coverage available for an incident at `timestamp`; an increase only applies
+    /// once `waiting_period` has passed since it was made
+    pub fn coverage_for_incident(&self, timestamp: i64, waiting_period: i64) -> u64 {
+        if timestamp < self.coverage_increased_at.saturating_add(waiting_period) {
+            self.coverage_amount.min(self.prior_coverage_amount)
+        } else {
+            self.coverage_amount
+        }
+    }
+
+    /// Unearned pooled premium for the paid time remaining after `now`
+    pub fn unearned_premium(&self, now: i64, billing_period: i64) -> Result<u64> {
+        let remaining = self.paid_through.saturating_sub(now).max(0);
+        let unearned = (self.period_premium as u128)
+            .checked_mul(remaining as u128)
+            .ok_or(NovaError::MathOverflow)?
+            .checked_div(billing_period as u128)
+            .ok_or(NovaError::MathOverflow)? as u64;
+        Ok(unearned.min(self.premiums_paid))
+    }
+
+    /// Whether premiums are overdue beyond the pool's grace period
+    pub fn is_overdue(&self, now: i64, grace_period: i64) -> bool {
+        now > self.paid_through.saturating_add(grace_period)
+    }
+}
+
+/// M-of-N multisig whose signer PDA can act as a pool authority
+#[account]
+#[derive(InitSpace)]
+pub struct Multisig {
+    /// Wallet that created the multisig (PDA seed)
+    pub creator: Pubkey,
+    
+    /// Creator-scoped index of this multisig (PDA seed)
+    pub multisig_index: u8,
+    
+    /// Owners allowed to propose and approve transactions (max 10)
+    #[max_len(10)]
+    pub signers: Vec<Pubkey>,
+    
+    /// Number of approvals required to execute a transaction
+    pub threshold: u8,
+    
+    /// Incremented whenever signers change, invalidating pending transactions
+    pub owner_set_seqno: u32,
+    
+    /// Number of transactions proposed so far (next transaction index)
+    pub transaction_count: u64,
+    
+    /// Bump of the multisig signer PDA
+    pub signer_bump: u8,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl Multisig {
+    /// Calculate space needed for Multisig account
+    pub const LEN: usize = 8 + // discriminator
+        32 + // creator
+        1 + // multisig_index
+        4 + (32 * 10) + // signers (vec + max 10 pubkeys)
+        1 + // threshold
+        4 + // owner_set_seqno
+        8 + // transaction_count
+        1 + // signer_bump
+        1; // bump
+
+    /// Maximum number of multisig signers
+    pub const MAX_SIGNERS: usize = 10;
+
+    /// Validate a signer set and threshold
+    pub fn validate_signers(signers: &[Pubkey], threshold: u8) -> Result<()> {
+        require!(
+            !signers.is_empty() && signers.len() <= Self::MAX_SIGNERS,
+            NovaError::InvalidMultisigThreshold
+        );
+        require!(
+            threshold > 0 && threshold as usize <= signers.len(),
+            NovaError::InvalidMultisigThreshold
+        );
+        for (i, signer) in signers.iter().enumerate() {
+            require!(
+                !signers[..i].contains(signer),
+                NovaError::DuplicateMultisigSigner
+            );
+        }
+        Ok(())
+    }
+
+    /// Position of `key` in the signer list
+    pub fn signer_index(&self, key: &Pubkey) -> Result<usize> {
+        self.signers
+            .iter()
+            .position(|s| s == key)
+            .ok_or_else(|| NovaError::NotMultisigSigner.into())
+    }
+}
+
+/// Account reference stored in a multisig transaction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct TransactionAccount {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+impl Space for TransactionAccount {
+    const INIT_SPACE: usize = 32 + 1 + 1; // pubkey + is_signer + is_writable
+}
+
+/// Instruction proposed to a multisig, executed once enough signers approve
+#[account]
+#[derive(InitSpace)]
+pub struct MultisigTransaction {
+    /// Multisig this transaction belongs to
+    pub multisig: Pubkey,
+    
+    /// Index of this transaction within the multisig
+    pub index: u64,
+    
+    /// Signer that proposed the transaction
+    pub proposer: Pubkey,
+    
+    /// Program to invoke
+    pub program_id: Pubkey,
+    
+    /// Accounts passed to the instruction (max 16)
+    #[max_len(16)]
+    pub accounts: Vec<TransactionAccount>,
+    
+    /// Instruction data (max 512 bytes)
+    #[max_len(512)]
+    pub data: Vec<u8>,
+    
+    /// Approval flag per multisig signer (same order as Multisig::signers)
+    #[max_len(10)]
+    pub approvals: Vec<bool>,
+    
+    /// Multisig owner_set_seqno when proposed
+    pub owner_set_seqno: u32,
+    
+    /// Whether the transaction has been executed
+    pub executed: bool,
+    
+    /// Timestamp when the transaction was proposed
+    pub created_at: i64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl MultisigTransaction {
+    /// Calculate space needed for MultisigTransaction account
+    pub const LEN: usize = 8 + // discriminator
+        32 + // multisig
+        8 + // index
+        32 + // proposer
+        32 + // program_id
+        4 + (34 * 16) + // accounts (vec + max 16 accounts)
+        4 + 512 + // data (vec + max 512 bytes)
+        4 + 10 + // approvals (vec + max 10 bools)
+        4 + // owner_set_seqno
+        1 + // executed
         8 + // created_at
         1; // bump
+
+    /// Number of approvals recorded
+    pub fn approval_count(&self) -> usize {
+        self.approvals.iter().filter(|a| **a).count()
+    }
 }
 
-/// User coverage account tracking individual member's insurance status
+/// Underwriter position tracking a capital provider's deposits and pending withdrawal
 #[account]
 #[derive(InitSpace)]
-pub struct UserCoverage {
-    /// User's wallet address
-    pub user: Pubkey,
+pub struct UnderwriterPosition {
+    /// Capital provider's wallet address
+    pub provider: Pubkey,
     
-    /// Insurance pool this coverage belongs to
+    /// Insurance pool this position belongs to
     pub pool: Pubkey,
     
-    /// Total premiums paid by this user
-    pub premiums_paid: u64,
-    
-    /// Timestamp of last premium payment
-    pub last_payment: i64,
-    
-    /// Whether coverage is currently active
-    pub coverage_active: bool,
+    /// Total USDC deposited by this provider
+    pub capital_deposited: u64,
     
-    /// Amount of coverage this user has
-    pub coverage_amount: u64,
+    /// Total USDC withdrawn by this provider
+    pub capital_withdrawn: u64,
     
-    /// Number of claims made by this user
-    pub claims_made: u8,
+    /// Shares queued for withdrawal (0 if no request is pending)
+    pub shares_pending_withdrawal: u64,
     
-    /// Timestamp when user joined the pool
-    pub joined_at: i64,
+    /// Timestamp of the pending withdrawal request
+    pub withdrawal_requested_at: i64,
     
     /// PDA bump seed
     pub bump: u8,
 }
 
-impl UserCoverage {
-    /// Calculate space needed for UserCoverage account
+impl UnderwriterPosition {
+    /// Calculate space needed for UnderwriterPosition account
     pub const LEN: usize = 8 + // discriminator
-        32 + // user
+        32 + // provider
         32 + // pool
-        8 + // premiums_paid
-        8 + // last_payment
-        1 + // coverage_active
-        8 + // coverage_amount
-        1 + // claims_made
-        8 + // joined_at
+        8 + // capital_deposited
+        8 + // capital_withdrawn
+        8 + // shares_pending_withdrawal
+        8 + // withdrawal_requested_at
         1; // bump
 }
 
@@ -203,6 +1324,19 @@ pub struct ValidatorStake {
     /// Timestamp of last validation
     pub last_validation: i64,
     
+    /// When the validator asked to unstake, if they are leaving the pool
+    pub unstake_requested_at: Option<i64>,
+    
+    /// USDC rewards earned from settled validations and not yet claimed
+    pub pending_rewards: u64,
+    
+    /// Total USDC rewards claimed
+    pub rewards_claimed: u64,
+    
+    /// Wallets the validator declared as their own or closely linked
+    #[max_len(5)]
+    pub linked_wallets: Vec<Pubkey>,
+    
     /// PDA bump seed
     pub bump: u8,
 }
@@ -216,13 +1350,63 @@ impl ValidatorStake {
         4 + // successful_validations
         4 + // reputation_score
         8 + // last_validation
+        1 + 8 + // unstake_requested_at
+        8 + // pending_rewards
+        8 + // rewards_claimed
+        4 + (32 * 5) + // linked_wallets (vec + max 5 pubkeys)
         1; // bump
     
+    /// Maximum number of linked wallets a validator can declare
+    pub const MAX_LINKED_WALLETS: usize = 5;
+    
     /// Initial reputation score for new validators
     pub const INITIAL_REPUTATION: u32 = 5000;
     
     /// Maximum reputation score
     pub const MAX_REPUTATION: u32 = 10000;
+    
+    /// Reputation gained for a vote that exactly matches the outcome
+    pub const MAX_VOTE_REWARD: u32 = 100;
+    
+    /// Reputation lost for a vote opposite to the outcome
+    pub const MAX_VOTE_PENALTY: u32 = 200;
+    
+    /// Reputation lost by an original majority voter overturned on appeal
+    pub const OVERTURNED_PENALTY: u32 = 200;
+    
+    /// Reputation lost by a validator replaced for missing a voting deadline
+    pub const MISSED_DEADLINE_PENALTY: u32 = 100;
+    
+    /// Reputation lost by a validator who committed a vote but never revealed it
+    pub const NON_REVEAL_PENALTY: u32 = 200;
+
+    /// USDC reward for a settled vote: the pool's base `validation_reward` scaled
+    /// by the vote's closeness to the outcome and by the validator's reputation
+    pub fn validation_reward(&self, base_reward: u64, closeness_bps: u16) -> Result<u64> {
+        Ok((base_reward as u128)
+            .checked_mul(closeness_bps as u128)
+            .ok_or(NovaError::MathOverflow)?
+            .checked_mul(self.reputation_score as u128)
+            .ok_or(NovaError::MathOverflow)?
+            .checked_div(BPS_DENOMINATOR as u128 * Self::MAX_REPUTATION as u128)
+            .ok_or(NovaError::MathOverflow)? as u64)
+    }
+
+    /// Apply the reputation change for a vote with the given closeness to the outcome,
+    /// scaling linearly from -MAX_VOTE_PENALTY (opposite) to +MAX_VOTE_REWARD (exact)
+    pub fn apply_vote_reputation(&mut self, closeness_bps: u16) {
+        let range = (Self::MAX_VOTE_REWARD + Self::MAX_VOTE_PENALTY) as u64;
+        let earned = (range * closeness_bps as u64 / BPS_DENOMINATOR as u64) as u32;
+
+        self.reputation_score = if earned >= Self::MAX_VOTE_PENALTY {
+            self.reputation_score
+                .saturating_add(earned - Self::MAX_VOTE_PENALTY)
+                .min(Self::MAX_REPUTATION)
+        } else {
+            self.reputation_score
+                .saturating_sub(Self::MAX_VOTE_PENALTY - earned)
+        };
+    }
 }
 
 /// Validator registry for a pool - tracks all validators
@@ -232,13 +1416,17 @@ pub struct ValidatorRegistry {
     /// The pool this registry belongs to
     pub pool: Pubkey,
     
-    /// List of active validators (max 100)
+    /// List of active validators with their selection weights (max 100)
     #[max_len(100)]
-    pub validators: Vec<Pubkey>,
+    pub validators: Vec<RegisteredValidator>,
     
     /// Total number of validators
     pub total_validators: u32,
     
+    /// Open claim assignments per validator; validators with none are omitted
+    #[max_len(100)]
+    pub assignments: Vec<ValidatorAssignment>,
+    
     /// PDA bump seed
     pub bump: u8,
 }
@@ -247,9 +1435,171 @@ impl ValidatorRegistry {
     /// Calculate space needed for ValidatorRegistry account
     pub const LEN: usize = 8 + // discriminator
         32 + // pool
-        4 + (32 * 100) + // validators (vec + max 100 pubkeys)
+        4 + (44 * 100) + // validators (vec + max 100 entries)
         4 + // total_validators
+        4 + (34 * 100) + // assignments (vec + max 100 entries)
+        1; // bump
+    
+    /// Maximum number of validators per registry
+    pub const MAX_VALIDATORS: usize = 100;
+
+    /// Whether the validator is in the selectable set
+    pub fn contains(&self, validator: &Pubkey) -> bool {
+        self.validators.iter().any(|v| v.validator == *validator)
+    }
+
+    /// Add a validator to the selectable set, or refresh its weight if already present
+    pub fn add_validator(&mut self, validator_stake: &ValidatorStake) -> Result<()> {
+        if self.contains(&validator_stake.validator) {
+            self.sync_validator(validator_stake);
+            return Ok(());
+        }
+        require!(
+            self.validators.len() < Self::MAX_VALIDATORS,
+            NovaError::InsufficientValidators
+        );
+        self.validators.push(RegisteredValidator {
+            validator: validator_stake.validator,
+            stake_amount: validator_stake.stake_amount,
+            reputation_score: validator_stake.reputation_score,
+        });
+        self.total_validators = self
+            .total_validators
+            .checked_add(1)
+            .ok_or(NovaError::InvalidCoverageAmount)?;
+        Ok(())
+    }
+
+    /// Refresh a registered validator's stake and reputation after either changes
+    pub fn sync_validator(&mut self, validator_stake: &ValidatorStake) {
+        if let Some(entry) = self
+            .validators
+            .iter_mut()
+            .find(|v| v.validator == validator_stake.validator)
+        {
+            entry.stake_amount = validator_stake.stake_amount;
+            entry.reputation_score = validator_stake.reputation_score;
+        }
+    }
+
+    /// Remove a validator from the selectable set; open assignments are kept
+    pub fn remove_validator(&mut self, validator: &Pubkey) {
+        if let Some(pos) = self.validators.iter().position(|v| v.validator == *validator) {
+            self.validators.remove(pos);
+            self.total_validators = self.total_validators.saturating_sub(1);
+        }
+    }
+
+    /// Number of open claims the validator is assigned to
+    pub fn open_claims(&self, validator: &Pubkey) -> u16 {
+        self.assignments
+            .iter()
+            .find(|a| a.validator == *validator)
+            .map_or(0, |a| a.open_claims)
+    }
+
+    /// Record that each of `validators` has been assigned to an open claim
+    pub fn record_assignments(&mut self, validators: &[Pubkey]) -> Result<()> {
+        for validator in validators {
+            match self.assignments.iter_mut().find(|a| a.validator == *validator) {
+                Some(assignment) => {
+                    assignment.open_claims = assignment
+                        .open_claims
+                        .checked_add(1)
+                        .ok_or(NovaError::MathOverflow)?;
+                }
+                None => {
+                    require!(
+                        self.assignments.len() < Self::MAX_VALIDATORS,
+                        NovaError::InsufficientValidators
+                    );
+                    self.assignments.push(ValidatorAssignment {
+                        validator: *validator,
+                        open_claims: 1,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Release one open-claim assignment for each of `validators`
+    pub fn release_assignments(&mut self, validators: &[Pubkey]) {
+        for validator in validators {
+            if let Some(pos) = self.assignments.iter().position(|a| a.validator == *validator) {
+                let assignment = &mut self.assignments[pos];
+                assignment.open_claims = assignment.open_claims.saturating_sub(1);
+                if assignment.open_claims == 0 {
+                    self.assignments.remove(pos);
+                }
+            }
+        }
+    }
+}
+
+/// Per-pool treasury holding lamports slashed from validator stakes
+#[account]
+#[derive(InitSpace)]
+pub struct SlashingTreasury {
+    /// The pool this treasury belongs to
+    pub pool: Pubkey,
+    
+    /// Total lamports slashed into the treasury
+    pub total_slashed: u64,
+    
+    /// Total lamports paid out to majority voters
+    pub total_redistributed: u64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl SlashingTreasury {
+    /// Calculate space needed for SlashingTreasury account
+    pub const LEN: usize = 8 + // discriminator
+        32 + // pool
+        8 + // total_slashed
+        8 + // total_redistributed
+        1; // bump
+}
+
+/// Per-pool accounting for the USDC validator rewards vault, funded from premiums
+#[account]
+#[derive(InitSpace)]
+pub struct ValidatorRewards {
+    /// The pool these rewards belong to
+    pub pool: Pubkey,
+    
+    /// USDC token account holding the rewards, owned by the pool PDA
+    pub vault: Pubkey,
+    
+    /// Total premium share paid into the rewards vault
+    pub total_funded: u64,
+    
+    /// Total rewards credited to validators
+    pub total_allocated: u64,
+    
+    /// Total rewards claimed by validators
+    pub total_claimed: u64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ValidatorRewards {
+    /// Calculate space needed for ValidatorRewards account
+    pub const LEN: usize = 8 + // discriminator
+        32 + // pool
+        32 + // vault
+        8 + // total_funded
+        8 + // total_allocated
+        8 + // total_claimed
         1; // bump
+
+    /// Funded rewards not yet credited to any validator
+    pub fn unallocated(&self) -> u64 {
+        self.total_funded.saturating_sub(self.total_allocated)
+    }
 }
 
 /// VRF state for random validator selection
@@ -347,9 +1697,61 @@ pub struct ClaimRequest {
     /// Timestamp when claim was resolved
     pub resolved_at: Option<i64>,
     
+    /// Claim amount validators settled on: median of approving votes' amounts
+    pub approved_amount: u64,
+    
+    /// Slashed lamports from this claim's minority voters reserved for its majority voters
+    pub slash_reward_pool: u64,
+    
     /// Actual payout amount (may differ from requested)
     pub payout_amount: Option<u64>,
     
+    /// Deductible withheld from the requested amount on approval
+    pub deductible_applied: u64,
+    
+    /// Member's coinsurance share withheld on approval
+    pub coinsurance_applied: u64,
+    
+    /// Payout forfeited on approval because it exceeded the member's remaining annual aggregate
+    pub annual_limit_applied: u64,
+    
+    /// Position of this claim in the pool's claim sequence (PDA seed)
+    pub sequence: u64,
+    
+    /// Claimant's claim nonce when submitted (ClaimIndex seed)
+    pub claimant_nonce: u64,
+    
+    /// Whether the claimant has appealed a rejection
+    pub appealed: bool,
+    
+    /// Appeal bond held in the pool vault until the appeal is decided
+    pub appeal_bond: u64,
+    
+    /// Whether the appeal panel overturned the original rejection
+    pub appeal_overturned: bool,
+    
+    /// Validators assigned in the original round, excluded from the appeal panel
+    #[max_len(10)]
+    pub original_validators: Vec<Pubkey>,
+    
+    /// Original majority voters; after an overturn, those still awaiting a reputation penalty
+    #[max_len(10)]
+    pub original_majority: Vec<Pubkey>,
+    
+    /// Deadline for the assigned validators to commit their votes (0 until validators are assigned)
+    pub voting_deadline: i64,
+    
+    /// Vote commitments from assigned validators (max 10)
+    #[max_len(10)]
+    pub commitments: Vec<VoteCommitment>,
+    
+    /// Deadline for revealing committed votes (0 until every assigned validator has committed)
+    pub reveal_deadline: i64,
+    
+    /// Validators who recused themselves from this claim over a conflict of interest
+    #[max_len(10)]
+    pub recused: Vec<Pubkey>,
+    
     /// PDA bump seed
     pub bump: u8,
 }
@@ -365,14 +1767,187 @@ impl ClaimRequest {
         8 + // incident_timestamp
         4 + 100 + // description (vec + max 100 chars)
         4 + (32 * 10) + // validators_assigned (vec + max 10 pubkeys)
-        4 + (245 * 10) + // validations (vec + max 10 validations)
+        4 + (254 * 10) + // validations (vec + max 10 validations)
         1 + // approvals
         1 + // rejections
         1 + // status
         1 + 32 + // vrf_result (option + 32 bytes)
         8 + // created_at
         1 + 8 + // resolved_at (option + i64)
+        8 + // approved_amount
+        8 + // slash_reward_pool
         1 + 8 + // payout_amount (option + u64)
+        8 + // deductible_applied
+        8 + // coinsurance_applied
+        8 + // annual_limit_applied
+        8 + // sequence
+        8 + // claimant_nonce
+        1 + // appealed
+        8 + // appeal_bond
+        1 + // appeal_overturned
+        4 + (32 * 10) + // original_validators (vec + max 10 pubkeys)
+        4 + (32 * 10) + // original_majority (vec + max 10 pubkeys)
+        8 + // voting_deadline
+        4 + (65 * 10) + // commitments (vec + max 10 commitments)
+        8 + // reveal_deadline
+        4 + (32 * 10) + // recused (vec + max 10 pubkeys)
+        1; // bump
+
+    /// Maximum validators assigned to a claim in one round
+    pub const MAX_VALIDATORS: usize = 10;
+
+    /// Commitment a validator submits for a vote:
+    /// hash(approve || approved_amount || salt || validator)
+    pub fn vote_hash(
+        validator: &Pubkey,
+        approve: bool,
+        approved_amount: u64,
+        salt: &[u8; 32],
+    ) -> [u8; 32] {
+        hashv(&[
+            &[approve as u8],
+            &approved_amount.to_le_bytes(),
+            salt,
+            validator.as_ref(),
+        ])
+        .to_bytes()
+    }
+
+    /// Median of the amounts proposed by approving votes (mean of the middle two
+    /// for an even count)
+    pub fn median_approved_amount(&self) -> u64 {
+        let mut amounts: Vec<u64> = self
+            .validations
+            .iter()
+            .filter(|v| v.approved)
+            .map(|v| v.approved_amount)
+            .collect();
+        if amounts.is_empty() {
+            return 0;
+        }
+        amounts.sort_unstable();
+
+        let mid = amounts.len() / 2;
+        if amounts.len() % 2 == 1 {
+            amounts[mid]
+        } else {
+            ((amounts[mid - 1] as u128 + amounts[mid] as u128) / 2) as u64
+        }
+    }
+
+    /// How close a vote was to the claim's outcome (basis points, 10000 = exact).
+    /// Rejections score fully on a rejected claim; approvals score by the distance
+    /// of their amount from the settled amount.
+    pub fn vote_closeness_bps(&self, validation: &Validation) -> u16 {
+        match (self.approved_amount > 0, validation.approved) {
+            (false, false) => BPS_DENOMINATOR,
+            (true, true) => {
+                let distance = validation.approved_amount.abs_diff(self.approved_amount) as u128;
+                let off_bps = (distance * BPS_DENOMINATOR as u128 / self.approved_amount as u128)
+                    .min(BPS_DENOMINATOR as u128) as u16;
+                BPS_DENOMINATOR - off_bps
+            }
+            _ => 0,
+        }
+    }
+
+    /// Whether a vote agreed with the claim's outcome (approve or reject),
+    /// regardless of how close an approving vote's amount was
+    pub fn voted_with_outcome(&self, validation: &Validation) -> bool {
+        validation.approved == (self.approved_amount > 0)
+    }
+
+    /// Whether every assigned validator has committed a vote
+    pub fn all_committed(&self) -> bool {
+        self.commitments.len() >= self.validators_assigned.len()
+    }
+
+    /// Swap each unresponsive validator for the next replacement, dropping those
+    /// left over once the replacements run out
+    pub fn replace_validators(&mut self, unresponsive: &[Pubkey], replacements: &[Pubkey]) {
+        let mut replacements = replacements.iter();
+        self.validators_assigned.retain_mut(|v| {
+            if !unresponsive.contains(v) {
+                return true;
+            }
+            match replacements.next() {
+                Some(replacement) => {
+                    *v = *replacement;
+                    true
+                }
+                None => false,
+            }
+        });
+    }
+
+    /// Validators that may never be selected for this claim: the claimant
+    /// themselves and any validator who recused
+    pub fn conflicted_validators(&self) -> Vec<Pubkey> {
+        let mut conflicted = vec![self.claimant];
+        conflicted.extend_from_slice(&self.recused);
+        conflicted
+    }
+}
+
+/// Per-member claim counter for a pool; outlives the member's coverage account so
+/// claim nonces are never reused after leaving and rejoining
+#[account]
+#[derive(InitSpace)]
+pub struct ClaimantNonce {
+    /// Pool the counter belongs to
+    pub pool: Pubkey,
+    
+    /// Member the counter belongs to
+    pub claimant: Pubkey,
+    
+    /// Nonce of the member's next claim
+    pub next_nonce: u64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ClaimantNonce {
+    /// Calculate space needed for ClaimantNonce account
+    pub const LEN: usize = 8 + // discriminator
+        32 + // pool
+        32 + // claimant
+        8 + // next_nonce
+        1; // bump
+}
+
+/// Lookup from a member's claim nonce to the claim, so a member's claims can be
+/// enumerated without knowing the pool-wide sequence numbers
+#[account]
+#[derive(InitSpace)]
+pub struct ClaimIndex {
+    /// Pool the claim was filed in
+    pub pool: Pubkey,
+    
+    /// Member who filed the claim
+    pub claimant: Pubkey,
+    
+    /// Member's claim nonce (PDA seed)
+    pub nonce: u64,
+    
+    /// Claim request account
+    pub claim: Pubkey,
+    
+    /// Pool-wide sequence number of the claim
+    pub sequence: u64,
+    
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl ClaimIndex {
+    /// Calculate space needed for ClaimIndex account
+    pub const LEN: usize = 8 + // discriminator
+        32 + // pool
+        32 + // claimant
+        8 + // nonce
+        32 + // claim
+        8 + // sequence
         1; // bump
 }
 
@@ -493,3 +2068,259 @@ impl PriorityScore {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::zeroed;
+
+    fn coverage(covered_from: i64, paid_through: i64) -> UserCoverage {
+        UserCoverage {
+            covered_from,
+            paid_through,
+            ..zeroed()
+        }
+    }
+
+    #[test]
+    fn incident_covered_only_inside_paid_period() {
+        let coverage = coverage(1_000, 2_000);
+        assert!(!coverage.covers_incident(999));
+        assert!(coverage.covers_incident(1_000));
+        assert!(coverage.covers_incident(2_000));
+        assert!(!coverage.covers_incident(2_001));
+    }
+
+    #[test]
+    fn overdue_only_after_grace_period() {
+        let coverage = coverage(0, 2_000);
+        assert!(!coverage.is_overdue(2_000, 100));
+        assert!(!coverage.is_overdue(2_100, 100));
+        assert!(coverage.is_overdue(2_101, 100));
+        assert!(coverage.is_overdue(2_001, 0));
+    }
+
+    #[test]
+    fn single_period_premium_has_no_discount() {
+        let pool = InsurancePool {
+            prepay_discount_bps: 1_000,
+            ..zeroed()
+        };
+        assert_eq!(pool.premium_for_periods(300, 1).unwrap(), (300, 0));
+    }
+
+    #[test]
+    fn prepaid_periods_get_discount() {
+        let pool = InsurancePool {
+            prepay_discount_bps: 1_000,
+            ..zeroed()
+        };
+        assert_eq!(pool.premium_for_periods(300, 12).unwrap(), (3_240, 360));
+    }
+
+    #[test]
+    fn prepaid_periods_without_discount_pay_gross() {
+        let pool: InsurancePool = zeroed();
+        assert_eq!(pool.premium_for_periods(300, 3).unwrap(), (900, 0));
+    }
+
+    #[test]
+    fn premium_for_periods_overflow_rejected() {
+        let pool: InsurancePool = zeroed();
+        assert!(pool.premium_for_periods(u64::MAX, 2).is_err());
+    }
+
+    fn priced_pool() -> InsurancePool {
+        InsurancePool {
+            pool_type: PoolType::Crop,
+            premium_amount: 100,
+            premium_rate_bps: 100,
+            risk_tier_multipliers_bps: [8_000, 10_000, 15_000],
+            ..zeroed()
+        }
+    }
+
+    #[test]
+    fn flat_premium_without_rate() {
+        let pool = InsurancePool {
+            premium_rate_bps: 0,
+            ..priced_pool()
+        };
+        assert_eq!(pool.quote_premium(1_000_000, RiskTier::Standard).unwrap(), 100);
+    }
+
+    #[test]
+    fn premium_proportional_to_coverage_with_floor() {
+        let pool = priced_pool();
+        assert_eq!(pool.quote_premium(50_000, RiskTier::Standard).unwrap(), 500);
+        assert_eq!(pool.quote_premium(1_000, RiskTier::Standard).unwrap(), 100);
+    }
+
+    #[test]
+    fn premium_scaled_by_pool_type_and_risk_tier() {
+        let pool = InsurancePool {
+            scale_by_pool_type: true,
+            ..priced_pool()
+        };
+        assert_eq!(pool.quote_premium(50_000, RiskTier::Standard).unwrap(), 750);
+        assert_eq!(pool.quote_premium(50_000, RiskTier::Low).unwrap(), 600);
+        assert_eq!(pool.quote_premium(50_000, RiskTier::High).unwrap(), 1_125);
+    }
+
+    #[test]
+    fn nav_excludes_unpaid_claims_and_appeal_bonds() {
+        let pool = InsurancePool {
+            yield_deposited: 2_000,
+            approved_unpaid_claims: 1_500,
+            appeal_bonds_held: 500,
+            ..zeroed()
+        };
+        assert_eq!(pool.net_asset_value(10_000).unwrap(), 10_000);
+        assert_eq!(pool.net_asset_value(0).unwrap(), 0);
+    }
+
+    fn cost_sharing_pool() -> InsurancePool {
+        InsurancePool {
+            deductible: 500,
+            coinsurance_bps: 2_000,
+            max_payout_per_claim: 5_000,
+            ..zeroed()
+        }
+    }
+
+    #[test]
+    fn payout_without_cost_sharing_is_amount_requested() {
+        let pool: InsurancePool = zeroed();
+        let payout = pool.calculate_payout(3_000).unwrap();
+        assert_eq!(payout.payout_amount, 3_000);
+        assert_eq!(payout.deductible_applied, 0);
+        assert_eq!(payout.coinsurance_applied, 0);
+    }
+
+    #[test]
+    fn payout_applies_deductible_then_coinsurance() {
+        let payout = cost_sharing_pool().calculate_payout(3_000).unwrap();
+        assert_eq!(payout.deductible_applied, 500);
+        assert_eq!(payout.coinsurance_applied, 500);
+        assert_eq!(payout.payout_amount, 2_000);
+    }
+
+    #[test]
+    fn payout_below_deductible_is_zero() {
+        let payout = cost_sharing_pool().calculate_payout(300).unwrap();
+        assert_eq!(payout.deductible_applied, 300);
+        assert_eq!(payout.coinsurance_applied, 0);
+        assert_eq!(payout.payout_amount, 0);
+    }
+
+    #[test]
+    fn payout_capped_per_claim() {
+        let payout = cost_sharing_pool().calculate_payout(100_000).unwrap();
+        assert_eq!(payout.coinsurance_applied, 19_900);
+        assert_eq!(payout.payout_amount, 5_000);
+    }
+
+    fn coverage_in_policy_year(policy_year_start: i64) -> UserCoverage {
+        UserCoverage {
+            policy_year_start,
+            claims_made: 2,
+            claimed_this_year: 1_000,
+            paid_this_year: 800,
+            ..zeroed()
+        }
+    }
+
+    #[test]
+    fn policy_year_kept_until_it_ends() {
+        let mut coverage = coverage_in_policy_year(1_000);
+        coverage.roll_policy_year(1_000 + UserCoverage::POLICY_YEAR - 1);
+        assert_eq!(coverage.policy_year_start, 1_000);
+        assert_eq!(coverage.claims_made, 2);
+        assert_eq!(coverage.claimed_this_year, 1_000);
+        assert_eq!(coverage.paid_this_year, 800);
+    }
+
+    #[test]
+    fn policy_year_rolls_and_resets_totals() {
+        let mut coverage = coverage_in_policy_year(1_000);
+        coverage.roll_policy_year(1_000 + UserCoverage::POLICY_YEAR);
+        assert_eq!(coverage.policy_year_start, 1_000 + UserCoverage::POLICY_YEAR);
+        assert_eq!(coverage.claims_made, 0);
+        assert_eq!(coverage.claimed_this_year, 0);
+        assert_eq!(coverage.paid_this_year, 0);
+    }
+
+    #[test]
+    fn policy_year_skips_whole_missed_years() {
+        let mut coverage = coverage_in_policy_year(1_000);
+        coverage.roll_policy_year(1_000 + 3 * UserCoverage::POLICY_YEAR + 5);
+        assert_eq!(coverage.policy_year_start, 1_000 + 3 * UserCoverage::POLICY_YEAR);
+    }
+
+    fn claim_with_votes(votes: &[(bool, u64)]) -> ClaimRequest {
+        ClaimRequest {
+            validations: votes
+                .iter()
+                .map(|&(approved, approved_amount)| Validation {
+                    validator: Pubkey::new_unique(),
+                    approved,
+                    approved_amount,
+                    reason: String::new(),
+                    timestamp: 0,
+                    settled: false,
+                })
+                .collect(),
+            ..zeroed()
+        }
+    }
+
+    #[test]
+    fn median_ignores_rejections() {
+        let claim = claim_with_votes(&[(true, 300), (false, 0), (true, 100), (true, 200)]);
+        assert_eq!(claim.median_approved_amount(), 200);
+    }
+
+    #[test]
+    fn median_of_even_count_is_mean_of_middle_two() {
+        let claim = claim_with_votes(&[(true, 400), (true, 100), (true, 201), (true, 900)]);
+        assert_eq!(claim.median_approved_amount(), 300);
+    }
+
+    #[test]
+    fn median_of_large_amounts_does_not_overflow() {
+        let claim = claim_with_votes(&[(true, u64::MAX), (true, u64::MAX - 2)]);
+        assert_eq!(claim.median_approved_amount(), u64::MAX - 1);
+    }
+
+    #[test]
+    fn median_without_approvals_is_zero() {
+        let claim = claim_with_votes(&[(false, 0), (false, 0)]);
+        assert_eq!(claim.median_approved_amount(), 0);
+    }
+
+    #[test]
+    fn imprecise_approval_still_votes_with_the_outcome() {
+        let mut claim = claim_with_votes(&[(true, 100), (true, 1_000), (false, 0)]);
+        claim.approved_amount = 1_000;
+        let [imprecise, exact, rejection] = [0, 1, 2].map(|i| &claim.validations[i]);
+        assert_eq!(claim.vote_closeness_bps(imprecise), 1_000);
+        assert!(claim.voted_with_outcome(imprecise));
+        assert!(claim.voted_with_outcome(exact));
+        assert!(!claim.voted_with_outcome(rejection));
+    }
+
+    #[test]
+    fn unreplaced_validators_are_dropped_from_the_panel() {
+        let [committed, missing_a, missing_b, replacement] =
+            [(); 4].map(|_| Pubkey::new_unique());
+        let mut claim = ClaimRequest {
+            validators_assigned: vec![missing_a, committed, missing_b],
+            ..zeroed()
+        };
+        claim.replace_validators(&[missing_a, missing_b], &[replacement]);
+        assert_eq!(claim.validators_assigned, vec![replacement, committed]);
+
+        claim.replace_validators(&[replacement], &[]);
+        assert_eq!(claim.validators_assigned, vec![committed]);
+    }
+}
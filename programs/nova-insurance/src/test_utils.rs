@@ -0,0 +1,8 @@
+//! Fixtures shared by unit tests
+
+use anchor_lang::prelude::*;
+
+/// Account with every field zeroed (first enum variant, `None`, empty vecs)
+pub fn zeroed<T: AnchorDeserialize>() -> T {
+    T::deserialize(&mut &[0u8; 4096][..]).unwrap()
+}